@@ -1,20 +1,20 @@
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-22
+//! Last Moddified --- 2026-10-19
 
 use crate::transform::{Position, Orientation,};
-use vector::{Vector, Sqrt, Trigonometry,};
+use vector::{Vector, Point, Sqrt, Trigonometry,};
 
 /// A line in space.
 #[derive(PartialEq, Eq, Clone, Copy, Hash,)]
 pub struct Line<Num,> {
   /// The origin point of the Line.
-  pub location: Vector<Num,>,
+  pub location: Point<Num,>,
   /// The direction of the Line.
   pub direction: Vector<Num,>,
 }
 
 impl<Num: Clone,> Position for Line<Num,> {
-  type Pos = Vector<Num,>;
+  type Pos = Point<Num,>;
 
   #[inline]
   fn position(&self,) -> Self::Pos { self.location.clone() }
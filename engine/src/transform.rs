@@ -1,9 +1,9 @@
 //! Defines traits for transformation of types.
 //! 
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-22
+//! Last Moddified --- 2026-10-19
 
-use vector::{Vector, Unit, Rotation, Sqrt, Trigonometry,};
+use vector::{Vector, Point, Unit, Rotation, Sqrt, Trigonometry,};
 use std::ops;
 
 /// A trait for types with a position attribute.
@@ -31,7 +31,7 @@ pub trait Position {
   }
 }
 
-impl<Num: Clone,> Position for Vector<Num,> {
+impl<Num: Clone,> Position for Point<Num,> {
   type Pos = Self;
 
   #[inline]
@@ -82,12 +82,12 @@ mod tests {
 
   #[test]
   fn test_position() {
-    let vec1 = Vector::new(1, 2, 3,);
-    let vec2 = Vector::new(-3, -2, -1,);
+    let point1 = Point::new(1, 2, 3,);
+    let point2 = Point::new(-3, -2, -1,);
 
-    assert_eq!(vec1.position(), vec1, "position failed",);
-    assert_eq!(*vec1.clone().translate(vec2,), (-2, 0, 2,).into(), "translate failed",);
-    assert_eq!(*vec1.clone().set_position(vec2,), vec2, "set_position failed",);
+    assert_eq!(point1.position(), point1, "position failed",);
+    assert_eq!(*point1.clone().translate(point2.to_vector(),), (-2, 0, 2,).into(), "translate failed",);
+    assert_eq!(*point1.clone().set_position(point2,), point2, "set_position failed",);
   }
   #[test]
   fn test_orientaion() {
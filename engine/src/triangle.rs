@@ -1,26 +1,28 @@
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-22
+//! Last Moddified --- 2026-10-19
 
 use crate::transform::{Position, Orientation,};
-use vector::{Vector, Rotation, Number, Sqrt, Trigonometry,};
+use vector::{Vector, Point, Rotation, Number, Sqrt, Trigonometry,};
 use std::ops;
 
 /// A triangle of three points in space.
 #[derive(PartialEq, Eq, Clone, Copy, Hash,)]
 pub struct Triangle<Num,> {
   /// The first point.
-  pub p1: Vector<Num,>,
+  pub p1: Point<Num,>,
   /// The second point.
-  pub p2: Vector<Num,>,
+  pub p2: Point<Num,>,
   /// The third point.
-  pub p3: Vector<Num,>,
+  pub p3: Point<Num,>,
 }
 
 impl<Num: Number + Clone,> Position for Triangle<Num,> {
-  type Pos = Vector<Num,>;
+  type Pos = Point<Num,>;
 
   fn position(&self,) -> Self::Pos {
-    (self.p1.clone() + self.p2.clone() + self.p3.clone()) / Number::from_isize(3,)
+    let offset = (self.p2.clone() - self.p1.clone()) + (self.p3.clone() - self.p1.clone());
+
+    self.p1.clone() + (offset / Num::from_isize(3,))
   }
   fn set_position(&mut self, pos: Self::Pos,) -> &mut Self {
     self.translate(pos - self.position(),)
@@ -46,11 +48,33 @@ impl<Num,> Orientation<Num,> for Triangle<Num,>
   fn set_direction(&mut self, direction: Vector<Num,>,) -> &mut Self {
     self.rotate(&Rotation::between(self.direction(), direction,),)
   }
+  /// Rotates the Triangle about the origin.
   fn rotate(&mut self, rotation: &Rotation<Num,>,) -> &mut Self {
-    self.p1.rotate(rotation,);
-    self.p2.rotate(rotation,);
-    self.p3.rotate(rotation,);
+    for point in [&mut self.p1, &mut self.p2, &mut self.p3,] {
+      *point = Point::from_vector(point.clone().to_vector().rotate(rotation,),);
+    }
 
     self
   }
 }
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_triangle_rotate() {
+    let mut triangle = Triangle {
+      p1: Point::new(1.0, 0.0, 0.0,),
+      p2: Point::new(1.0, 1.0, 0.0,),
+      p3: Point::new(1.0, 0.0, 1.0,),
+    };
+    let expected = [Point::new(0.0, 1.0, 0.0,), Point::new(-1.0, 1.0, 0.0,), Point::new(0.0, 1.0, 1.0,),];
+
+    triangle.rotate(&Rotation::between(Vector::X, Vector::Y,),);
+    for (point, expected,) in [triangle.p1, triangle.p2, triangle.p3,].iter().zip(expected.iter(),) {
+      assert!(Point::distance_squared(*point, *expected,) < 1e-12, "rotate failed",);
+    }
+    assert!(Vector::distance_squared(triangle.direction(), Vector::Y,) < 1e-12, "Rotated direction failed",);
+  }
+}
@@ -0,0 +1,266 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use core::{fmt::{self, Write,}, error::Error,};
+
+/// The most fractional decimal digits which are formatted or parsed.
+/// 
+/// A value with `n` fractional bits has at most `n` fractional decimal digits so every
+/// value is formatted and parsed exactly.
+const DIGITS: usize = 130;
+
+/// Formats the value `bits / 2^shift` exactly, respecting formatting flags such as
+/// precision.
+/// 
+/// Without a precision the fewest digits which parse back to the same value are written.
+/// 
+/// # Params
+/// 
+/// bits --- The raw bits of the value.  
+/// shift --- The number of fractional bits.  
+/// fmt --- The formatter to write to.  
+pub(crate) fn format(bits: i128, shift: u32, fmt: &mut fmt::Formatter,) -> fmt::Result {
+  let one = 1u128 << shift;
+  let mut integer = bits.unsigned_abs() >> shift;
+  let mut remainder = bits.unsigned_abs() & (one - 1);
+  let mut digits = [0u8; DIGITS];
+  let mut len = 0;
+  //The zeros requested beyond `DIGITS`.
+  let mut zeros = 0;
+
+  match fmt.precision() {
+    Some(precision) => {
+      //Digits beyond the exact expansion are all zero.
+      while len < precision.min(DIGITS,) { digits[len] = next(&mut remainder, shift,); len += 1 }
+      zeros = precision - len;
+
+      //Round half to even like the float formatting.
+      let odd = if len == 0 { integer % 2 == 1 } else { digits[len - 1] % 2 == 1 };
+
+      if 2 * remainder > one || (2 * remainder == one && odd) {
+        round_up(&mut integer, &mut digits[..len],)
+      }
+    },
+    None => {
+      //The digits written so far identify the value once the error is within half of a
+      //bit, checking against `10^len` in units of `2^-shift * 10^-len`.
+      let mut scale = 1u128;
+
+      loop {
+        if 2 * remainder < scale {
+          if 2 * (one - remainder) < scale && one - remainder < remainder {
+            round_up(&mut integer, &mut digits[..len],)
+          }
+          break
+        } else if 2 * (one - remainder) < scale {
+          round_up(&mut integer, &mut digits[..len],);
+          break
+        }
+
+        digits[len] = next(&mut remainder, shift,);
+        len += 1;
+        scale = scale.saturating_mul(10,);
+      }
+
+      while len > 0 && digits[len - 1] == 0 { len -= 1 }
+    },
+  }
+
+  //The integer part of an `i128` has at most 39 digits.
+  let mut buffer = [0u8; 40 + DIGITS];
+  let mut end = 0;
+  let mut power = 1u128;
+
+  while integer / power >= 10 { power *= 10 }
+  loop {
+    buffer[end] = b'0' + (integer / power) as u8;
+    integer %= power;
+    end += 1;
+    if power == 1 { break }
+    power /= 10;
+  }
+  if len > 0 {
+    buffer[end] = b'.';
+    end += 1;
+    for &digit in &digits[..len] { buffer[end] = b'0' + digit; end += 1 }
+  }
+
+  //The buffer only contains ASCII digits and the decimal point.
+  let text = core::str::from_utf8(&buffer[..end],).map_err(|_,| fmt::Error,)?;
+
+  if zeros == 0 { return fmt.pad_integral(bits >= 0, "", text,) }
+
+  //The trailing zeros do not fit in the buffer so the padding is written around them.
+  let sign = if bits < 0 { "-" } else if fmt.sign_plus() { "+" } else { "" };
+  let padding = fmt.width().unwrap_or(0,).saturating_sub(sign.len() + text.len() + zeros,);
+  let (fill, before,) = if fmt.sign_aware_zero_pad() {
+    fmt.write_str(sign,)?;
+    ('0', padding,)
+  } else {
+    let before = match fmt.align() {
+      Some(fmt::Alignment::Left) => 0,
+      Some(fmt::Alignment::Center) => padding / 2,
+      _ => padding,
+    };
+
+    (fmt.fill(), before,)
+  };
+
+  for _ in 0..before { fmt.write_char(fill,)? }
+  if !fmt.sign_aware_zero_pad() { fmt.write_str(sign,)? }
+  fmt.write_str(text,)?;
+  for _ in 0..zeros { fmt.write_char('0',)? }
+  for _ in before..padding { fmt.write_char(fill,)? }
+
+  Ok(())
+}
+
+/// Returns the next fractional digit of `remainder / 2^shift`, leaving the rest in
+/// `remainder`.
+fn next(remainder: &mut u128, shift: u32,) -> u8 {
+  *remainder *= 10;
+
+  let digit = (*remainder >> shift) as u8;
+
+  *remainder &= (1 << shift) - 1;
+  digit
+}
+
+/// Adds one to the last digit of `digits`, carrying into `integer`.
+fn round_up(integer: &mut u128, digits: &mut [u8],) {
+  for digit in digits.iter_mut().rev() {
+    if *digit == 9 { *digit = 0 } else { *digit += 1; return }
+  }
+
+  *integer += 1;
+}
+
+/// Parses a decimal number as the raw bits of a fixed point number with `shift`
+/// fractional bits, rounding to the nearest value.
+/// 
+/// Accepts an optional sign, digits with an optional decimal point and an optional
+/// exponent, such as `-1.5e3`.
+/// 
+/// # Params
+/// 
+/// from --- The string to parse.  
+/// shift --- The number of fractional bits.  
+pub(crate) fn parse(from: &str, shift: u32,) -> Result<i128, ParseFixedError> {
+  if from.is_empty() { return Err(ParseFixedError::Empty) }
+
+  let bytes = from.as_bytes();
+  let (negative, bytes,) = match bytes[0] {
+    b'-' => (true, &bytes[1..],),
+    b'+' => (false, &bytes[1..],),
+    _ => (false, bytes,),
+  };
+  let (mantissa, exponent,) = match bytes.iter().position(|&byte,| byte == b'e' || byte == b'E',) {
+    Some(index) => (&bytes[..index], exponent(&bytes[index + 1..],)?,),
+    None => (bytes, 0,),
+  };
+  let (integer, fraction,) = match mantissa.iter().position(|&byte,| byte == b'.',) {
+    Some(index) => (&mantissa[..index], &mantissa[index + 1..],),
+    None => (mantissa, &[][..],),
+  };
+
+  if integer.len() + fraction.len() == 0 { return Err(ParseFixedError::Invalid) }
+
+  //The position of the decimal point within the digits once the exponent is applied.
+  let point = integer.len() as i64 + exponent;
+  let mut whole = 0u128;
+  let mut digits = [0u8; DIGITS];
+  let mut len = 0;
+
+  for (index, &byte,) in integer.iter().chain(fraction,).enumerate() {
+    if !byte.is_ascii_digit() { return Err(ParseFixedError::Invalid) }
+
+    let digit = byte - b'0';
+    let index = index as i64;
+
+    if index < point {
+      whole = whole.checked_mul(10,).and_then(|whole,| whole.checked_add(digit as u128,),)
+        .ok_or(ParseFixedError::Overflow,)?;
+    } else if index - point < DIGITS as i64 {
+      //Digits past `DIGITS` are too small to change the rounded value.
+      len = (index - point + 1) as usize;
+      digits[len - 1] = digit;
+    }
+  }
+
+  let count = (integer.len() + fraction.len()) as i64;
+
+  for _ in count..point {
+    if whole == 0 { break }
+    whole = whole.checked_mul(10,).ok_or(ParseFixedError::Overflow,)?;
+  }
+
+  //Double the fraction once per fractional bit, each carry out is the next bit.
+  let mut bits = 0u128;
+
+  for _ in 0..shift {
+    let mut carry = 0;
+
+    for digit in digits[..len].iter_mut().rev() {
+      let double = *digit * 2 + carry;
+
+      *digit = double % 10;
+      carry = double / 10;
+    }
+
+    bits = (bits << 1) | carry as u128;
+  }
+
+  //Round half way cases away from zero.
+  if len > 0 && digits[0] >= 5 { bits += 1 }
+  if whole > i128::MAX as u128 >> shift { return Err(ParseFixedError::Overflow) }
+
+  let bits = (whole << shift).checked_add(bits,).filter(|&bits,| bits <= i128::MAX as u128,)
+    .ok_or(ParseFixedError::Overflow,)? as i128;
+
+  Ok(if negative { -bits } else { bits })
+}
+
+/// Parses the exponent of a decimal number, saturating far outside the range of any
+/// fixed point number.
+fn exponent(bytes: &[u8],) -> Result<i64, ParseFixedError> {
+  let (negative, bytes,) = match bytes.first() {
+    Some(b'-') => (true, &bytes[1..],),
+    Some(b'+') => (false, &bytes[1..],),
+    _ => (false, bytes,),
+  };
+
+  if bytes.is_empty() { return Err(ParseFixedError::Invalid) }
+
+  let mut exponent = 0i64;
+
+  for &byte in bytes {
+    if !byte.is_ascii_digit() { return Err(ParseFixedError::Invalid) }
+
+    exponent = (exponent * 10 + (byte - b'0') as i64).min(1 << 20,);
+  }
+
+  Ok(if negative { -exponent } else { exponent })
+}
+
+/// The error returned when parsing a fixed point number fails.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum ParseFixedError {
+  /// The string was empty.
+  Empty,
+  /// The string was not a decimal number.
+  Invalid,
+  /// The number is outside the range of the fixed point type.
+  Overflow,
+}
+
+impl fmt::Display for ParseFixedError {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    match self {
+      ParseFixedError::Empty => fmt.write_str("cannot parse a fixed point number from an empty string",),
+      ParseFixedError::Invalid => fmt.write_str("invalid fixed point literal",),
+      ParseFixedError::Overflow => fmt.write_str("number is outside the range of the fixed point type",),
+    }
+  }
+}
+
+impl Error for ParseFixedError {}
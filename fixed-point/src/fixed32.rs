@@ -1,10 +1,14 @@
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-21
+//! Last Moddified --- 2026-10-19
 
 use typenum::Unsigned;
-use std::{
+use crate::decimal::{self, ParseFixedError,};
+use core::{
 	ops, fmt,
+  hash::{Hash, Hasher,},
+  cmp::Ordering,
   convert::TryInto,
+  str::FromStr,
 	marker::PhantomData,
 };
 
@@ -12,7 +16,6 @@ use std::{
 /// 
 /// The `Shift` parameter determines how many of the lower bits are used for the
 /// fractional components.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash,)]
 pub struct Fixed32<Shift: Unsigned,>(i32, PhantomData<Shift>,);
 
 impl<Shift: Unsigned,> Fixed32<Shift,> {
@@ -26,6 +29,16 @@ impl<Shift: Unsigned,> Fixed32<Shift,> {
   /// Converts this value to an `f32`.
   #[inline]
   pub const fn to_f32(self,) -> f32 { self.0 as f32 / Self::F32SHIFT }
+  /// Returns the raw bits of this value.
+  #[inline]
+  pub const fn to_bits(self,) -> i32 { self.0 }
+  /// Creates a value from its raw bits.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The raw bits of the value, including the fractional bits.  
+  #[inline]
+  pub const fn from_bits(bits: i32,) -> Self { Fixed32(bits, PhantomData,) }
 }
 
 impl<Shift: Unsigned,> From<i32,> for Fixed32<Shift,> {
@@ -38,10 +51,20 @@ impl<Shift: Unsigned,> Into<i32,> for Fixed32<Shift,> {
   fn into(self,) -> i32 { self.to_i32() }
 }
 
+/// Rounds half way cases away from zero.
+#[cfg(feature = "std",)]
+#[inline]
+fn round(num: f32,) -> f32 { num.round() }
+
+/// Rounds half way cases away from zero.
+#[cfg(not(feature = "std",),)]
+#[inline]
+fn round(num: f32,) -> f32 { libm::roundf(num,) }
+
 impl<Shift: Unsigned,> From<f32,> for Fixed32<Shift,> {
   #[inline]
   fn from(from: f32,) -> Self { Fixed32(
-    (from * Self::F32SHIFT).round() as i32,
+    round(from * Self::F32SHIFT,) as i32,
     PhantomData,
   ) }
 }
@@ -58,6 +81,33 @@ impl<Shift: Unsigned,> Clone for Fixed32<Shift,> {
 
 impl<Shift: Unsigned,> Copy for Fixed32<Shift,> {}
 
+impl<Shift: Unsigned,> Default for Fixed32<Shift,> {
+  #[inline]
+  fn default() -> Self { Fixed32(0, PhantomData,) }
+}
+
+impl<Shift: Unsigned,> PartialEq for Fixed32<Shift,> {
+  #[inline]
+  fn eq(&self, rhs: &Self,) -> bool { self.0 == rhs.0 }
+}
+
+impl<Shift: Unsigned,> Eq for Fixed32<Shift,> {}
+
+impl<Shift: Unsigned,> PartialOrd for Fixed32<Shift,> {
+  #[inline]
+  fn partial_cmp(&self, rhs: &Self,) -> Option<Ordering> { Some(self.cmp(rhs,),) }
+}
+
+impl<Shift: Unsigned,> Ord for Fixed32<Shift,> {
+  #[inline]
+  fn cmp(&self, rhs: &Self,) -> Ordering { self.0.cmp(&rhs.0,) }
+}
+
+impl<Shift: Unsigned,> Hash for Fixed32<Shift,> {
+  #[inline]
+  fn hash<H: Hasher,>(&self, state: &mut H,) { self.0.hash(state,) }
+}
+
 impl<Shift: Unsigned,> ops::Neg for Fixed32<Shift,> {
   type Output = Self;
 
@@ -143,6 +193,18 @@ impl<Shift: Unsigned,> ops::DivAssign<i32> for Fixed32<Shift,> {
   fn div_assign(&mut self, rhs: i32,) { self.0 /= rhs }
 }
 
+impl<Shift: Unsigned,> ops::Rem for Fixed32<Shift,> {
+  type Output = Self;
+
+  #[inline]
+  fn rem(mut self, rhs: Self,) -> Self::Output { self %= rhs; self }
+}
+
+impl<Shift: Unsigned,> ops::RemAssign for Fixed32<Shift,> {
+  #[inline]
+  fn rem_assign(&mut self, rhs: Self,) { self.0 %= rhs.0 }
+}
+
 impl<Shift: Unsigned,> fmt::Debug for Fixed32<Shift,> {
   #[inline]
   fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
@@ -152,6 +214,47 @@ impl<Shift: Unsigned,> fmt::Debug for Fixed32<Shift,> {
   }
 }
 
+/// Formats the exact decimal value, respecting formatting flags such as precision.
+/// 
+/// Without a precision the fewest digits which parse back to the same value are written.
+impl<Shift: Unsigned,> fmt::Display for Fixed32<Shift,> {
+  #[inline]
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { decimal::format(self.0 as i128, Shift::U32, fmt,) }
+}
+
+/// Parses a decimal number, such as `-1.5` or `2.5e-3`, rounding it to the nearest fixed
+/// point value.
+impl<Shift: Unsigned,> FromStr for Fixed32<Shift,> {
+  type Err = ParseFixedError;
+
+  fn from_str(from: &str,) -> Result<Self, Self::Err> {
+    decimal::parse(from, Shift::U32,)?.try_into().map(Self::from_bits,)
+    .map_err(|_,| ParseFixedError::Overflow,)
+  }
+}
+
+/// Serialises the raw bits so values round trip exactly.
+/// 
+/// Included with feature "serde".
+#[cfg(feature = "serde",)]
+impl<Shift: Unsigned,> serde::Serialize for Fixed32<Shift,> {
+  #[inline]
+  fn serialize<S: serde::Serializer,>(&self, serializer: S,) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i32(self.0,)
+  }
+}
+
+/// Deserialises the raw bits of the value.
+/// 
+/// Included with feature "serde".
+#[cfg(feature = "serde",)]
+impl<'de, Shift: Unsigned,> serde::Deserialize<'de> for Fixed32<Shift,> {
+  #[inline]
+  fn deserialize<D: serde::Deserializer<'de>,>(deserializer: D,) -> Result<Self, D::Error> {
+    <i32 as serde::Deserialize>::deserialize(deserializer,).map(Self::from_bits,)
+  }
+}
+
 #[cfg(test,)]
 mod tests {
   use super::*;
@@ -179,5 +282,34 @@ mod tests {
 
     let num = Fixed32::<U16>::from(1) / 2;
     assert_eq!(num.to_f32(), 0.5, "Division i32 failed",);
+
+    let num = Fixed32::<U16>::from(7.5) % Fixed32::<U16>::from(2);
+    assert_eq!(num.to_f32(), 1.5, "Remainder failed",);
+
+    assert_eq!(format!("{:.2}", Fixed32::<U16>::from(1.5),), "1.50", "Display failed",);
+    assert_eq!("-2.25".parse::<Fixed32<U16>>(), Ok(Fixed32::from(-2.25,),), "FromStr failed",);
+  }
+  #[test]
+  fn test_fixed32_decimal() {
+    let (max, min,) = (Fixed32::<U16>::from_bits(i32::MAX,), Fixed32::<U16>::from_bits(i32::MIN,),);
+    assert_eq!(max.to_string(), "32767.99998", "Display max failed",);
+    assert_eq!(min.to_string(), "-32768", "Display min failed",);
+    assert_eq!(Fixed32::<U16>::from_bits(1,).to_string(), "0.00002", "Display epsilon failed",);
+    assert_eq!(format!("{:.20}", Fixed32::<U16>::from_bits(1,),), "0.00001525878906250000", "Display precision failed",);
+    assert_eq!(format!("{:+08.1}", Fixed32::<U16>::from(2.25,),), "+00002.2", "Display flags failed",);
+    assert_eq!(format!("{:.200}", Fixed32::<U16>::from(-1.5,),), format!("{:.200}", -1.5f64,), "Display long precision failed",);
+    assert_eq!(format!("{:+0210.200}", Fixed32::<U16>::from(1.5,),), format!("{:+0210.200}", 1.5f64,), "Display long flags failed",);
+    assert_eq!(format!("{:*^210.200}", Fixed32::<U16>::from(1.5,),), format!("{:*^210.200}", 1.5f64,), "Display long align failed",);
+    assert_eq!(max.to_string().parse(), Ok(max,), "Round trip max failed",);
+    assert_eq!(min.to_string().parse(), Ok(min,), "Round trip min failed",);
+
+    assert_eq!("30000.0001".parse::<Fixed32<U16>>().map(Fixed32::to_bits,), Ok(1966080007,), "FromStr rounding failed",);
+    assert_eq!("1.5e1".parse::<Fixed32<U16>>(), Ok(Fixed32::from(15,),), "FromStr exponent failed",);
+    assert_eq!(".5".parse::<Fixed32<U16>>(), Ok(Fixed32::from(0.5,),), "FromStr fraction failed",);
+    assert_eq!("32768".parse::<Fixed32<U16>>(), Err(ParseFixedError::Overflow), "FromStr overflow failed",);
+    assert_eq!("32767.99999".parse::<Fixed32<U16>>(), Ok(max,), "FromStr max failed",);
+    assert_eq!("32767.999995".parse::<Fixed32<U16>>(), Err(ParseFixedError::Overflow), "FromStr rounding overflow failed",);
+    assert_eq!("".parse::<Fixed32<U16>>(), Err(ParseFixedError::Empty), "FromStr empty failed",);
+    assert_eq!("1.2.3".parse::<Fixed32<U16>>(), Err(ParseFixedError::Invalid), "FromStr invalid failed",);
   }
 }
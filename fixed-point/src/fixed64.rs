@@ -1,10 +1,14 @@
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-21
+//! Last Moddified --- 2026-10-19
 
 use typenum::Unsigned;
-use std::{
+use crate::decimal::{self, ParseFixedError,};
+use core::{
 	ops, fmt,
+  hash::{Hash, Hasher,},
+  cmp::Ordering,
   convert::TryInto,
+  str::FromStr,
 	marker::PhantomData,
 };
 
@@ -12,7 +16,6 @@ use std::{
 /// 
 /// The `Shift` parameter determines how many of the lower bits are used for the
 /// fractional components.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash,)]
 pub struct Fixed64<Shift: Unsigned,>(i64, PhantomData<Shift>,);
 
 impl<Shift: Unsigned,> Fixed64<Shift,> {
@@ -26,6 +29,16 @@ impl<Shift: Unsigned,> Fixed64<Shift,> {
   /// Converts this value to an `f64`.
   #[inline]
   pub const fn to_f64(self,) -> f64 { self.0 as f64 / Self::F32SHIFT }
+  /// Returns the raw bits of this value.
+  #[inline]
+  pub const fn to_bits(self,) -> i64 { self.0 }
+  /// Creates a value from its raw bits.
+  /// 
+  /// # Params
+  /// 
+  /// bits --- The raw bits of the value, including the fractional bits.  
+  #[inline]
+  pub const fn from_bits(bits: i64,) -> Self { Fixed64(bits, PhantomData,) }
 }
 
 impl<Shift: Unsigned,> From<i64,> for Fixed64<Shift,> {
@@ -38,10 +51,20 @@ impl<Shift: Unsigned,> Into<i64,> for Fixed64<Shift,> {
   fn into(self,) -> i64 { self.to_i64() }
 }
 
+/// Rounds half way cases away from zero.
+#[cfg(feature = "std",)]
+#[inline]
+fn round(num: f64,) -> f64 { num.round() }
+
+/// Rounds half way cases away from zero.
+#[cfg(not(feature = "std",),)]
+#[inline]
+fn round(num: f64,) -> f64 { libm::round(num,) }
+
 impl<Shift: Unsigned,> From<f64,> for Fixed64<Shift,> {
   #[inline]
   fn from(from: f64,) -> Self { Fixed64(
-    (from * Self::F32SHIFT).round() as i64,
+    round(from * Self::F32SHIFT,) as i64,
     PhantomData,
   ) }
 }
@@ -58,6 +81,33 @@ impl<Shift: Unsigned,> Clone for Fixed64<Shift,> {
 
 impl<Shift: Unsigned,> Copy for Fixed64<Shift,> {}
 
+impl<Shift: Unsigned,> Default for Fixed64<Shift,> {
+  #[inline]
+  fn default() -> Self { Fixed64(0, PhantomData,) }
+}
+
+impl<Shift: Unsigned,> PartialEq for Fixed64<Shift,> {
+  #[inline]
+  fn eq(&self, rhs: &Self,) -> bool { self.0 == rhs.0 }
+}
+
+impl<Shift: Unsigned,> Eq for Fixed64<Shift,> {}
+
+impl<Shift: Unsigned,> PartialOrd for Fixed64<Shift,> {
+  #[inline]
+  fn partial_cmp(&self, rhs: &Self,) -> Option<Ordering> { Some(self.cmp(rhs,),) }
+}
+
+impl<Shift: Unsigned,> Ord for Fixed64<Shift,> {
+  #[inline]
+  fn cmp(&self, rhs: &Self,) -> Ordering { self.0.cmp(&rhs.0,) }
+}
+
+impl<Shift: Unsigned,> Hash for Fixed64<Shift,> {
+  #[inline]
+  fn hash<H: Hasher,>(&self, state: &mut H,) { self.0.hash(state,) }
+}
+
 impl<Shift: Unsigned,> ops::Neg for Fixed64<Shift,> {
   type Output = Self;
 
@@ -143,6 +193,18 @@ impl<Shift: Unsigned,> ops::DivAssign<i64> for Fixed64<Shift,> {
   fn div_assign(&mut self, rhs: i64,) { self.0 /= rhs }
 }
 
+impl<Shift: Unsigned,> ops::Rem for Fixed64<Shift,> {
+  type Output = Self;
+
+  #[inline]
+  fn rem(mut self, rhs: Self,) -> Self::Output { self %= rhs; self }
+}
+
+impl<Shift: Unsigned,> ops::RemAssign for Fixed64<Shift,> {
+  #[inline]
+  fn rem_assign(&mut self, rhs: Self,) { self.0 %= rhs.0 }
+}
+
 impl<Shift: Unsigned,> fmt::Debug for Fixed64<Shift,> {
   #[inline]
   fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
@@ -152,6 +214,47 @@ impl<Shift: Unsigned,> fmt::Debug for Fixed64<Shift,> {
   }
 }
 
+/// Formats the exact decimal value, respecting formatting flags such as precision.
+/// 
+/// Without a precision the fewest digits which parse back to the same value are written.
+impl<Shift: Unsigned,> fmt::Display for Fixed64<Shift,> {
+  #[inline]
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { decimal::format(self.0 as i128, Shift::U32, fmt,) }
+}
+
+/// Parses a decimal number, such as `-1.5` or `2.5e-3`, rounding it to the nearest fixed
+/// point value.
+impl<Shift: Unsigned,> FromStr for Fixed64<Shift,> {
+  type Err = ParseFixedError;
+
+  fn from_str(from: &str,) -> Result<Self, Self::Err> {
+    decimal::parse(from, Shift::U32,)?.try_into().map(Self::from_bits,)
+    .map_err(|_,| ParseFixedError::Overflow,)
+  }
+}
+
+/// Serialises the raw bits so values round trip exactly.
+/// 
+/// Included with feature "serde".
+#[cfg(feature = "serde",)]
+impl<Shift: Unsigned,> serde::Serialize for Fixed64<Shift,> {
+  #[inline]
+  fn serialize<S: serde::Serializer,>(&self, serializer: S,) -> Result<S::Ok, S::Error> {
+    serializer.serialize_i64(self.0,)
+  }
+}
+
+/// Deserialises the raw bits of the value.
+/// 
+/// Included with feature "serde".
+#[cfg(feature = "serde",)]
+impl<'de, Shift: Unsigned,> serde::Deserialize<'de> for Fixed64<Shift,> {
+  #[inline]
+  fn deserialize<D: serde::Deserializer<'de>,>(deserializer: D,) -> Result<Self, D::Error> {
+    <i64 as serde::Deserialize>::deserialize(deserializer,).map(Self::from_bits,)
+  }
+}
+
 #[cfg(test,)]
 mod tests {
   use super::*;
@@ -179,5 +282,25 @@ mod tests {
 
     let num = Fixed64::<U16>::from(1) / 2;
     assert_eq!(num.to_f64(), 0.5, "Division i64 failed",);
+
+    let num = Fixed64::<U16>::from(7.5) % Fixed64::<U16>::from(2);
+    assert_eq!(num.to_f64(), 1.5, "Remainder failed",);
+
+    assert_eq!(format!("{:.2}", Fixed64::<U16>::from(1.5),), "1.50", "Display failed",);
+    assert_eq!("-2.25".parse::<Fixed64<U16>>(), Ok(Fixed64::from(-2.25,),), "FromStr failed",);
+  }
+  #[test]
+  fn test_fixed64_decimal() {
+    use typenum::U32;
+
+    let (max, min,) = (Fixed64::<U32>::from_bits(i64::MAX,), Fixed64::<U32>::from_bits(i64::MIN,),);
+    assert_eq!(max.to_string(), "2147483647.9999999998", "Display max failed",);
+    assert_eq!(min.to_string(), "-2147483648", "Display min failed",);
+    assert_eq!(max.to_string().parse(), Ok(max,), "Round trip max failed",);
+    assert_eq!(min.to_string().parse(), Ok(min,), "Round trip min failed",);
+
+    assert_eq!("3e-10".parse::<Fixed64<U32>>(), Ok(Fixed64::from_bits(1,),), "FromStr rounding failed",);
+    assert_eq!("-1e-10".parse::<Fixed64<U32>>(), Ok(Fixed64::from_bits(0,),), "FromStr small failed",);
+    assert_eq!("2147483647.9999999999".parse::<Fixed64<U32>>(), Err(ParseFixedError::Overflow), "FromStr overflow failed",);
   }
 }
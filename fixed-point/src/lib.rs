@@ -1,15 +1,23 @@
 //!	Defines types for fixed point arithmetic.
 //! 
+//! The crate is `no_std` when the default "std" feature is disabled, rounding is then
+//! provided by `libm` through the "libm" feature.
+//! 
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-07
+//! Last Moddified --- 2026-10-19
 
 #![deny(missing_docs,)]
-#![feature(const_fn,)]
+#![cfg_attr(not(any(feature = "std", test,),), no_std,)]
+
+#[cfg(not(any(feature = "std", feature = "libm",),),)]
+compile_error!("either the \"std\" or the \"libm\" feature must be enabled",);
 
 pub use typenum::{consts, Unsigned, UInt, UTerm, bit::{B0, B1,},};
 
+mod decimal;
 mod fixed32;
 mod fixed64;
 
+pub use self::decimal::ParseFixedError;
 pub use self::fixed32::*;
 pub use self::fixed64::*;
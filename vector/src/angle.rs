@@ -0,0 +1,195 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::ops;
+
+/// An angle measured in radians.
+#[derive(PartialEq, Eq, PartialOrd, Clone, Copy, Debug, Hash,)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,),)]
+pub struct Radians<Num,>(pub Num,);
+
+/// An angle measured in degrees.
+#[derive(PartialEq, Eq, PartialOrd, Clone, Copy, Debug, Hash,)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,),)]
+pub struct Degrees<Num,>(pub Num,);
+
+/// Returns `num` wrapped into the range `[0, period)`.
+fn wrap_positive<Num: Number + Clone,>(num: Num, period: Num,) -> Num {
+  let num = num % period.clone();
+
+  if num < Num::ZERO {
+    let num = num + period.clone();
+
+    //Tiny negative values can round up to `period`.
+    if num < period { num } else { Num::ZERO }
+  } else { num }
+}
+
+/// Returns `num` wrapped into the range `(-period / 2, period / 2]`.
+fn wrap_signed<Num: Number + Clone,>(num: Num, period: Num,) -> Num {
+  let num = wrap_positive(num, period.clone(),);
+
+  if num > period.clone() / Num::from_isize(2,) { num - period } else { num }
+}
+
+impl<Num: Trigonometry,> Radians<Num,> {
+  /// The sine of this angle.
+  #[inline]
+  pub fn sin(self,) -> Num { self.0.sin() }
+  /// The cosine of this angle.
+  #[inline]
+  pub fn cos(self,) -> Num { self.0.cos() }
+  /// The tangent of this angle.
+  #[inline]
+  pub fn tan(self,) -> Num { self.0.tan() }
+  /// The sine and cosine of this angle.
+  #[inline]
+  pub fn sin_cos(self,) -> (Num, Num,)
+    where Num: Clone, { self.0.sin_cos() }
+}
+
+impl<Num: Trigonometry + Clone,> Radians<Num,> {
+  /// Wraps this angle into the range `[0, 2pi)`.
+  #[inline]
+  pub fn wrap_positive(self,) -> Self {
+    Radians(wrap_positive(self.0, Num::TAU,),)
+  }
+  /// Wraps this angle into the range `(-pi, pi]`.
+  #[inline]
+  pub fn wrap_signed(self,) -> Self {
+    Radians(wrap_signed(self.0, Num::TAU,),)
+  }
+}
+
+impl<Num: Trigonometry + Clone,> Degrees<Num,> {
+  /// The sine of this angle.
+  #[inline]
+  pub fn sin(self,) -> Num { Radians::from(self,).sin() }
+  /// The cosine of this angle.
+  #[inline]
+  pub fn cos(self,) -> Num { Radians::from(self,).cos() }
+  /// The tangent of this angle.
+  #[inline]
+  pub fn tan(self,) -> Num { Radians::from(self,).tan() }
+  /// The sine and cosine of this angle.
+  #[inline]
+  pub fn sin_cos(self,) -> (Num, Num,) { Radians::from(self,).sin_cos() }
+}
+
+impl<Num: Number + Clone,> Degrees<Num,> {
+  /// Wraps this angle into the range `[0, 360)`.
+  #[inline]
+  pub fn wrap_positive(self,) -> Self { Degrees(wrap_positive(self.0, Num::from_isize(360,),),) }
+  /// Wraps this angle into the range `(-180, 180]`.
+  #[inline]
+  pub fn wrap_signed(self,) -> Self { Degrees(wrap_signed(self.0, Num::from_isize(360,),),) }
+}
+
+impl<Num: Trigonometry,> From<Degrees<Num,>> for Radians<Num,> {
+  #[inline]
+  fn from(from: Degrees<Num,>,) -> Self { Radians(from.0.to_radians(),) }
+}
+
+impl<Num: Trigonometry,> From<Radians<Num,>> for Degrees<Num,> {
+  #[inline]
+  fn from(from: Radians<Num,>,) -> Self { Degrees(from.0.to_degrees(),) }
+}
+
+/// Implements the arithmetic operators for an angle type.
+macro_rules! impl_angle_ops {
+  ($angle:ident,) => {
+    impl<Num: ops::Neg<Output = Num>,> ops::Neg for $angle<Num,> {
+      type Output = Self;
+
+      #[inline]
+      fn neg(self,) -> Self::Output { $angle(-self.0,) }
+    }
+
+    impl<Num: ops::Add<Output = Num>,> ops::Add for $angle<Num,> {
+      type Output = Self;
+
+      #[inline]
+      fn add(self, rhs: Self,) -> Self::Output { $angle(self.0 + rhs.0,) }
+    }
+
+    impl<Num: Clone + ops::Add<Output = Num>,> ops::AddAssign for $angle<Num,> {
+      #[inline]
+      fn add_assign(&mut self, rhs: Self,) { self.0 = self.0.clone() + rhs.0 }
+    }
+
+    impl<Num: ops::Sub<Output = Num>,> ops::Sub for $angle<Num,> {
+      type Output = Self;
+
+      #[inline]
+      fn sub(self, rhs: Self,) -> Self::Output { $angle(self.0 - rhs.0,) }
+    }
+
+    impl<Num: Clone + ops::Sub<Output = Num>,> ops::SubAssign for $angle<Num,> {
+      #[inline]
+      fn sub_assign(&mut self, rhs: Self,) { self.0 = self.0.clone() - rhs.0 }
+    }
+
+    impl<Num: ops::Mul<Output = Num>,> ops::Mul<Num> for $angle<Num,> {
+      type Output = Self;
+
+      #[inline]
+      fn mul(self, rhs: Num,) -> Self::Output { $angle(self.0 * rhs,) }
+    }
+
+    impl<Num: Clone + ops::Mul<Output = Num>,> ops::MulAssign<Num> for $angle<Num,> {
+      #[inline]
+      fn mul_assign(&mut self, rhs: Num,) { self.0 = self.0.clone() * rhs }
+    }
+
+    impl<Num: ops::Div<Output = Num>,> ops::Div<Num> for $angle<Num,> {
+      type Output = Self;
+
+      #[inline]
+      fn div(self, rhs: Num,) -> Self::Output { $angle(self.0 / rhs,) }
+    }
+
+    impl<Num: Clone + ops::Div<Output = Num>,> ops::DivAssign<Num> for $angle<Num,> {
+      #[inline]
+      fn div_assign(&mut self, rhs: Num,) { self.0 = self.0.clone() / rhs }
+    }
+
+    impl<Num: ops::Div<Output = Num>,> ops::Div for $angle<Num,> {
+      type Output = Num;
+
+      #[inline]
+      fn div(self, rhs: Self,) -> Self::Output { self.0 / rhs.0 }
+    }
+  };
+}
+
+impl_angle_ops!(Radians,);
+impl_angle_ops!(Degrees,);
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+  use std::f64::consts::{PI, FRAC_PI_2,};
+
+  #[test]
+  fn test_angle() {
+    assert_approx_eq!(Radians::from(Degrees(90.0,),), Radians(FRAC_PI_2,), "Convertion to radians failed",);
+    assert_approx_eq!(Degrees::from(Radians(PI,),), Degrees(180.0,), "Convertion to degrees failed",);
+    assert_eq!(Radians(1.0,) + Radians(2.0,) - Radians(0.5,), Radians(2.5,), "Arithmetic failed",);
+    assert_eq!(-(Degrees(30.0,) * 2.0) / 4.0, Degrees(-15.0,), "Scaling failed",);
+    assert_eq!(Degrees(90.0,) / Degrees(30.0,), 3.0, "Ratio failed",);
+
+    assert_approx_eq!(Degrees(30.0,).sin(), 0.5, "sin failed",);
+    assert_approx_eq!(Radians(PI,).cos(), -1.0, "cos failed",);
+
+    assert_eq!(Degrees(370.0,).wrap_positive(), Degrees(10.0,), "wrap_positive failed 1",);
+    assert_eq!(Degrees(-10.0,).wrap_positive(), Degrees(350.0,), "wrap_positive failed 2",);
+    assert_eq!(Degrees(360.0,).wrap_positive(), Degrees(0.0,), "wrap_positive failed 3",);
+    assert_eq!(Degrees(180.0,).wrap_signed(), Degrees(180.0,), "wrap_signed failed 1",);
+    assert_eq!(Degrees(-180.0,).wrap_signed(), Degrees(180.0,), "wrap_signed failed 2",);
+    assert_eq!(Degrees(270.0,).wrap_signed(), Degrees(-90.0,), "wrap_signed failed 3",);
+    assert_approx_eq!(Radians(-FRAC_PI_2,).wrap_positive(), Radians(3.0 * FRAC_PI_2,), "wrap_positive failed 4",);
+    assert_approx_eq!(Radians(3.0 * PI,).wrap_signed(), Radians(PI,), "wrap_signed failed 4",);
+    assert!(Radians(-1e-20f64,).wrap_positive().0 < 2.0 * PI, "wrap_positive reached 2pi",);
+  }
+}
@@ -0,0 +1,469 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+#[cfg(feature = "fixed-point",)]
+use fixed_point::Unsigned;
+
+/// Defines approximate equality between values which are subject to rounding error.
+pub trait ApproxEq<Rhs = Self,> {
+  /// The type used to express the tolerance of a comparison.
+  type Epsilon;
+
+  /// The default absolute tolerance.
+  fn default_epsilon() -> Self::Epsilon;
+  /// The default relative tolerance.
+  fn default_max_relative() -> Self::Epsilon;
+  /// The default number of units in the last place of tolerance.
+  fn default_max_ulps() -> u32;
+  /// Returns `true` if the absolute difference between the values is within `epsilon`.
+  /// 
+  /// # Params
+  /// 
+  /// rhs --- The value to compare against.  
+  /// epsilon --- The largest absolute difference considered equal.  
+  fn abs_diff_eq(&self, rhs: &Rhs, epsilon: Self::Epsilon,) -> bool;
+  /// Returns `true` if the values are within `epsilon` of each other or their
+  /// difference is within `max_relative` of the larger value.
+  /// 
+  /// # Params
+  /// 
+  /// rhs --- The value to compare against.  
+  /// epsilon --- The largest absolute difference considered equal.  
+  /// max_relative --- The largest difference relative to the larger value considered equal.  
+  fn relative_eq(&self, rhs: &Rhs, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool;
+  /// Returns `true` if the values are within `epsilon` of each other or there are at
+  /// most `max_ulps` representable values between them.
+  /// 
+  /// # Params
+  /// 
+  /// rhs --- The value to compare against.  
+  /// epsilon --- The largest absolute difference considered equal.  
+  /// max_ulps --- The largest number of units in the last place considered equal.  
+  fn ulps_eq(&self, rhs: &Rhs, epsilon: Self::Epsilon, max_ulps: u32,) -> bool;
+  /// Returns `true` if the values are relatively equal using the default tolerances.
+  #[inline]
+  fn approx_eq(&self, rhs: &Rhs,) -> bool {
+    self.relative_eq(rhs, Self::default_epsilon(), Self::default_max_relative(),)
+  }
+}
+
+/// Implements the default tolerances of [ApproxEq] as those of `$num`.
+macro_rules! delegate_defaults {
+  ($num:ident,) => {
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon { $num::default_epsilon() }
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon { $num::default_max_relative() }
+    #[inline]
+    fn default_max_ulps() -> u32 { $num::default_max_ulps() }
+  };
+}
+
+macro_rules! impl_float {
+  ($float:ident, $bits:ident,) => {
+    impl ApproxEq for $float {
+      type Epsilon = Self;
+
+      #[inline]
+      fn default_epsilon() -> Self::Epsilon { $float::EPSILON }
+      #[inline]
+      fn default_max_relative() -> Self::Epsilon { $float::EPSILON }
+      #[inline]
+      fn default_max_ulps() -> u32 { 4 }
+      #[inline]
+      fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+        (self - rhs).abs() <= epsilon
+      }
+      fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+        //Handles infinities of the same sign.
+        if self == rhs { return true }
+        if self.is_infinite() || rhs.is_infinite() { return false }
+
+        let diff = (self - rhs).abs();
+        if diff <= epsilon { return true }
+
+        diff <= self.abs().max(rhs.abs(),) * max_relative
+      }
+      fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+        if self.abs_diff_eq(rhs, epsilon,) { return true }
+        //Values of differing signs are only equal if they are both zero.
+        if self.is_sign_negative() != rhs.is_sign_negative() { return self == rhs }
+
+        let lhs = self.to_bits() as $bits;
+        let rhs = rhs.to_bits() as $bits;
+
+        (lhs - rhs).unsigned_abs() <= max_ulps as _
+      }
+    }
+  };
+}
+
+impl_float!(f32, i32,);
+impl_float!(f64, i64,);
+
+/// Implements [ApproxEq] for a fixed point type, comparing the bits as `$wide` so the
+/// differences cannot overflow.
+#[cfg(feature = "fixed-point",)]
+macro_rules! impl_fixed {
+  ($fixed:ident, $wide:ident,) => {
+    /// Included with feature "fixed-point".
+    impl<Shift: Unsigned,> ApproxEq for fixed_point::$fixed<Shift,> {
+      type Epsilon = Self;
+
+      #[inline]
+      fn default_epsilon() -> Self::Epsilon { Self::from_bits(1,) }
+      #[inline]
+      fn default_max_relative() -> Self::Epsilon { Self::from_bits(1,) }
+      #[inline]
+      fn default_max_ulps() -> u32 { 4 }
+      #[inline]
+      fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+        (self.to_bits() as $wide - rhs.to_bits() as $wide).abs() <= epsilon.to_bits() as $wide
+      }
+      fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+        if self.abs_diff_eq(rhs, epsilon,) { return true }
+
+        let diff = (self.to_bits() as $wide - rhs.to_bits() as $wide).abs();
+        let largest = (self.to_bits() as $wide).abs().max((rhs.to_bits() as $wide).abs(),);
+
+        diff <= (largest * max_relative.to_bits() as $wide) >> Shift::U32
+      }
+      #[inline]
+      fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+        self.abs_diff_eq(rhs, epsilon,)
+          || (self.to_bits() as $wide - rhs.to_bits() as $wide).abs() <= max_ulps as $wide
+      }
+    }
+  };
+}
+
+#[cfg(feature = "fixed-point",)]
+impl_fixed!(Fixed32, i64,);
+#[cfg(feature = "fixed-point",)]
+impl_fixed!(Fixed64, i128,);
+
+impl<Num,> ApproxEq for Vector<Num,>
+  where Num: ApproxEq, Num::Epsilon: Clone, {
+  type Epsilon = Num::Epsilon;
+
+  delegate_defaults!(Num,);
+  fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+    self.x.abs_diff_eq(&rhs.x, epsilon.clone(),)
+    && self.y.abs_diff_eq(&rhs.y, epsilon.clone(),)
+    && self.z.abs_diff_eq(&rhs.z, epsilon,)
+  }
+  fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+    self.x.relative_eq(&rhs.x, epsilon.clone(), max_relative.clone(),)
+    && self.y.relative_eq(&rhs.y, epsilon.clone(), max_relative.clone(),)
+    && self.z.relative_eq(&rhs.z, epsilon, max_relative,)
+  }
+  fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+    self.x.ulps_eq(&rhs.x, epsilon.clone(), max_ulps,)
+    && self.y.ulps_eq(&rhs.y, epsilon.clone(), max_ulps,)
+    && self.z.ulps_eq(&rhs.z, epsilon, max_ulps,)
+  }
+}
+
+impl<Num,> ApproxEq for Point<Num,>
+  where Num: ApproxEq, Num::Epsilon: Clone, {
+  type Epsilon = Num::Epsilon;
+
+  delegate_defaults!(Num,);
+  fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+    self.x.abs_diff_eq(&rhs.x, epsilon.clone(),)
+    && self.y.abs_diff_eq(&rhs.y, epsilon.clone(),)
+    && self.z.abs_diff_eq(&rhs.z, epsilon,)
+  }
+  fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+    self.x.relative_eq(&rhs.x, epsilon.clone(), max_relative.clone(),)
+    && self.y.relative_eq(&rhs.y, epsilon.clone(), max_relative.clone(),)
+    && self.z.relative_eq(&rhs.z, epsilon, max_relative,)
+  }
+  fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+    self.x.ulps_eq(&rhs.x, epsilon.clone(), max_ulps,)
+    && self.y.ulps_eq(&rhs.y, epsilon.clone(), max_ulps,)
+    && self.z.ulps_eq(&rhs.z, epsilon, max_ulps,)
+  }
+}
+
+impl<Num,> ApproxEq for Unit<Num,>
+  where Num: ApproxEq, Num::Epsilon: Clone, {
+  type Epsilon = Num::Epsilon;
+
+  delegate_defaults!(Num,);
+  #[inline]
+  fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+    self.as_vector().abs_diff_eq(rhs.as_vector(), epsilon,)
+  }
+  #[inline]
+  fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+    self.as_vector().relative_eq(rhs.as_vector(), epsilon, max_relative,)
+  }
+  #[inline]
+  fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+    self.as_vector().ulps_eq(rhs.as_vector(), epsilon, max_ulps,)
+  }
+}
+
+impl<Num,> ApproxEq<Vector<Num,>> for Unit<Num,>
+  where Num: ApproxEq, Num::Epsilon: Clone, {
+  type Epsilon = Num::Epsilon;
+
+  delegate_defaults!(Num,);
+  #[inline]
+  fn abs_diff_eq(&self, rhs: &Vector<Num,>, epsilon: Self::Epsilon,) -> bool {
+    self.as_vector().abs_diff_eq(rhs, epsilon,)
+  }
+  #[inline]
+  fn relative_eq(&self, rhs: &Vector<Num,>, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+    self.as_vector().relative_eq(rhs, epsilon, max_relative,)
+  }
+  #[inline]
+  fn ulps_eq(&self, rhs: &Vector<Num,>, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+    self.as_vector().ulps_eq(rhs, epsilon, max_ulps,)
+  }
+}
+
+/// Implements [ApproxEq] for an angle type.
+macro_rules! impl_angle {
+  ($angle:ident,) => {
+    impl<Num: ApproxEq,> ApproxEq for $angle<Num,> {
+      type Epsilon = Num::Epsilon;
+
+      delegate_defaults!(Num,);
+      #[inline]
+      fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+        self.0.abs_diff_eq(&rhs.0, epsilon,)
+      }
+      #[inline]
+      fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+        self.0.relative_eq(&rhs.0, epsilon, max_relative,)
+      }
+      #[inline]
+      fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+        self.0.ulps_eq(&rhs.0, epsilon, max_ulps,)
+      }
+    }
+  };
+}
+
+impl_angle!(Radians,);
+impl_angle!(Degrees,);
+
+/// Compares the axis and angle of the [Rotation]s component wise.
+/// 
+/// Equivalent rotations with a negated axis and angle do not compare as equal.
+impl<Num,> ApproxEq for Rotation<Num,>
+  where Num: ApproxEq, Num::Epsilon: Clone, {
+  type Epsilon = Num::Epsilon;
+
+  delegate_defaults!(Num,);
+  fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+    self.axis.abs_diff_eq(&rhs.axis, epsilon.clone(),)
+    && self.angle.abs_diff_eq(&rhs.angle, epsilon,)
+  }
+  fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+    self.axis.relative_eq(&rhs.axis, epsilon.clone(), max_relative.clone(),)
+    && self.angle.relative_eq(&rhs.angle, epsilon, max_relative,)
+  }
+  fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+    self.axis.ulps_eq(&rhs.axis, epsilon.clone(), max_ulps,)
+    && self.angle.ulps_eq(&rhs.angle, epsilon, max_ulps,)
+  }
+}
+
+impl<Num,> ApproxEq for Bivector<Num,>
+  where Num: ApproxEq, Num::Epsilon: Clone, {
+  type Epsilon = Num::Epsilon;
+
+  delegate_defaults!(Num,);
+  fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+    self.xy.abs_diff_eq(&rhs.xy, epsilon.clone(),)
+    && self.yz.abs_diff_eq(&rhs.yz, epsilon.clone(),)
+    && self.zx.abs_diff_eq(&rhs.zx, epsilon,)
+  }
+  fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+    self.xy.relative_eq(&rhs.xy, epsilon.clone(), max_relative.clone(),)
+    && self.yz.relative_eq(&rhs.yz, epsilon.clone(), max_relative.clone(),)
+    && self.zx.relative_eq(&rhs.zx, epsilon, max_relative,)
+  }
+  fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+    self.xy.ulps_eq(&rhs.xy, epsilon.clone(), max_ulps,)
+    && self.yz.ulps_eq(&rhs.yz, epsilon.clone(), max_ulps,)
+    && self.zx.ulps_eq(&rhs.zx, epsilon, max_ulps,)
+  }
+}
+
+/// Compares the scalar and bivector of the [Rotor]s component wise.
+/// 
+/// Negated Rotors perform the same rotation but do not compare as equal.
+impl<Num,> ApproxEq for Rotor<Num,>
+  where Num: ApproxEq, Num::Epsilon: Clone, {
+  type Epsilon = Num::Epsilon;
+
+  delegate_defaults!(Num,);
+  fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+    self.scalar.abs_diff_eq(&rhs.scalar, epsilon.clone(),)
+    && self.bivector.abs_diff_eq(&rhs.bivector, epsilon,)
+  }
+  fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+    self.scalar.relative_eq(&rhs.scalar, epsilon.clone(), max_relative.clone(),)
+    && self.bivector.relative_eq(&rhs.bivector, epsilon, max_relative,)
+  }
+  fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+    self.scalar.ulps_eq(&rhs.scalar, epsilon.clone(), max_ulps,)
+    && self.bivector.ulps_eq(&rhs.bivector, epsilon, max_ulps,)
+  }
+}
+
+/// Compares the rows of the [Matrix]s component wise.
+impl<Num,> ApproxEq for Matrix<Num,>
+  where Num: ApproxEq, Num::Epsilon: Clone, {
+  type Epsilon = Num::Epsilon;
+
+  delegate_defaults!(Num,);
+  fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+    self.rows.iter().zip(rhs.rows.iter(),)
+    .all(|(lhs, rhs,),| lhs.abs_diff_eq(rhs, epsilon.clone(),),)
+  }
+  fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+    self.rows.iter().zip(rhs.rows.iter(),)
+    .all(|(lhs, rhs,),| lhs.relative_eq(rhs, epsilon.clone(), max_relative.clone(),),)
+  }
+  fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+    self.rows.iter().zip(rhs.rows.iter(),)
+    .all(|(lhs, rhs,),| lhs.ulps_eq(rhs, epsilon.clone(), max_ulps,),)
+  }
+}
+
+impl<T: ApproxEq, Space,> ApproxEq for Tagged<T, Space,> {
+  type Epsilon = T::Epsilon;
+
+  delegate_defaults!(T,);
+  #[inline]
+  fn abs_diff_eq(&self, rhs: &Self, epsilon: Self::Epsilon,) -> bool {
+    (**self).abs_diff_eq(rhs, epsilon,)
+  }
+  #[inline]
+  fn relative_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon,) -> bool {
+    (**self).relative_eq(rhs, epsilon, max_relative,)
+  }
+  #[inline]
+  fn ulps_eq(&self, rhs: &Self, epsilon: Self::Epsilon, max_ulps: u32,) -> bool {
+    (**self).ulps_eq(rhs, epsilon, max_ulps,)
+  }
+}
+
+/// Asserts that two values are approximately equal using [ApproxEq::approx_eq].
+/// 
+/// Accepts an optional format message in the same manner as `assert_eq`.
+#[macro_export]
+macro_rules! assert_approx_eq {
+  ($lhs:expr, $rhs:expr $(,)?) => {
+    $crate::__assert_approx!(approx_eq, $lhs, $rhs, [],)
+  };
+  ($lhs:expr, $rhs:expr, $($arg:tt)+) => {
+    $crate::__assert_approx!(approx_eq, $lhs, $rhs, [], $($arg)+)
+  };
+}
+
+/// Asserts that two values are approximately equal using [ApproxEq::abs_diff_eq].
+/// 
+/// Accepts an optional format message in the same manner as `assert_eq`.
+#[macro_export]
+macro_rules! assert_abs_diff_eq {
+  ($lhs:expr, $rhs:expr, $epsilon:expr $(,)?) => {
+    $crate::__assert_approx!(abs_diff_eq, $lhs, $rhs, [$epsilon,],)
+  };
+  ($lhs:expr, $rhs:expr, $epsilon:expr, $($arg:tt)+) => {
+    $crate::__assert_approx!(abs_diff_eq, $lhs, $rhs, [$epsilon,], $($arg)+)
+  };
+}
+
+/// Asserts that two values are approximately equal using [ApproxEq::relative_eq].
+/// 
+/// Accepts an optional format message in the same manner as `assert_eq`.
+#[macro_export]
+macro_rules! assert_relative_eq {
+  ($lhs:expr, $rhs:expr, $epsilon:expr, $max_relative:expr $(,)?) => {
+    $crate::__assert_approx!(relative_eq, $lhs, $rhs, [$epsilon, $max_relative,],)
+  };
+  ($lhs:expr, $rhs:expr, $epsilon:expr, $max_relative:expr, $($arg:tt)+) => {
+    $crate::__assert_approx!(relative_eq, $lhs, $rhs, [$epsilon, $max_relative,], $($arg)+)
+  };
+}
+
+/// Asserts that two values are approximately equal using [ApproxEq::ulps_eq].
+/// 
+/// Accepts an optional format message in the same manner as `assert_eq`.
+#[macro_export]
+macro_rules! assert_ulps_eq {
+  ($lhs:expr, $rhs:expr, $epsilon:expr, $max_ulps:expr $(,)?) => {
+    $crate::__assert_approx!(ulps_eq, $lhs, $rhs, [$epsilon, $max_ulps,],)
+  };
+  ($lhs:expr, $rhs:expr, $epsilon:expr, $max_ulps:expr, $($arg:tt)+) => {
+    $crate::__assert_approx!(ulps_eq, $lhs, $rhs, [$epsilon, $max_ulps,], $($arg)+)
+  };
+}
+
+#[doc(hidden,)]
+#[macro_export]
+macro_rules! __assert_approx {
+  ($cmp:ident, $lhs:expr, $rhs:expr, [$($tol:expr,)*],) => {
+    match (&$lhs, &$rhs,) {
+      (lhs, rhs,) => assert!(
+        $crate::ApproxEq::$cmp(lhs, rhs, $($tol,)*),
+        "assertion failed: `(left {} right)`\n  left: `{:?}`,\n right: `{:?}`",
+        stringify!($cmp), lhs, rhs,
+      ),
+    }
+  };
+  ($cmp:ident, $lhs:expr, $rhs:expr, [$($tol:expr,)*], $($arg:tt)+) => {
+    match (&$lhs, &$rhs,) {
+      (lhs, rhs,) => assert!($crate::ApproxEq::$cmp(lhs, rhs, $($tol,)*), $($arg)+),
+    }
+  };
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_approx_eq() {
+    assert!(1.0f32.approx_eq(&(1.0 + f32::EPSILON),), "approx_eq failed",);
+    assert!(!1.0f32.approx_eq(&1.001,), "approx_eq too loose",);
+    assert!(1.0f32.abs_diff_eq(&1.001, 0.01,), "abs_diff_eq failed",);
+    assert!(1000.0f64.relative_eq(&1001.0, 0.0, 0.01,), "relative_eq failed",);
+    assert!(!1000.0f64.relative_eq(&1011.0, 0.0, 0.01,), "relative_eq too loose",);
+    assert!(1.0f32.ulps_eq(&f32::from_bits(1.0f32.to_bits() + 2,), 0.0, 2,), "ulps_eq failed",);
+    assert!(!1.0f32.ulps_eq(&f32::from_bits(1.0f32.to_bits() + 3,), 0.0, 2,), "ulps_eq too loose",);
+    assert!(!1.0f32.ulps_eq(&-1.0, 0.0, 4,), "ulps_eq ignored sign",);
+    assert!(!f32::NAN.approx_eq(&f32::NAN,), "NaN compared equal",);
+    assert!(f32::INFINITY.approx_eq(&f32::INFINITY,), "Infinity compared unequal",);
+
+    assert_approx_eq!(Vector::new(1.0, 2.0, 3.0,), Vector::new(1.0, 2.0, 3.0 + f64::EPSILON,),);
+    assert_abs_diff_eq!(Vector::new(1.0, 2.0, 3.0,), Vector::new(1.01, 1.99, 3.0,), 0.02, "Vector failed",);
+    assert_relative_eq!(
+      Rotation::new(Vector::new(0.0, 0.0, 1.0,).unit(), Radians(100.0,),),
+      Rotation::new(Vector::new(0.0, 0.0, 1.0,).unit(), Radians(100.01,),),
+      0.0, 0.001,
+    );
+    assert_ulps_eq!(Vector::new(0.0f32, 0.0, 1.0,).unit(), Vector::new(0.0, 0.0, 1.0,), 0.0, 0,);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_approx_eq_fixed() {
+    use fixed_point::{Fixed32, Fixed64, consts::U16,};
+
+    let one = Fixed32::<U16>::from(1,);
+    assert!(one.approx_eq(&Fixed32::from_bits(one.to_bits() + 1,),), "approx_eq Fixed32 failed",);
+    assert!(!one.approx_eq(&Fixed32::from_bits(one.to_bits() + 2,),), "approx_eq Fixed32 too loose",);
+    assert!(one.ulps_eq(&Fixed32::from_bits(one.to_bits() - 4,), Fixed32::from(0,), 4,), "ulps_eq Fixed32 failed",);
+
+    let big = Fixed64::<U16>::from(1000,);
+    assert!(big.relative_eq(&Fixed64::from(1001,), Fixed64::from(0,), Fixed64::from(0.01,),), "relative_eq Fixed64 failed",);
+    assert!(!big.relative_eq(&Fixed64::from(1011,), Fixed64::from(0,), Fixed64::from(0.01,),), "relative_eq Fixed64 too loose",);
+  }
+}
@@ -0,0 +1,343 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use alloc::vec::Vec;
+use core::{ops, iter::FromIterator,};
+
+/// A collection of [Vector]s stored as separate arrays of x, y and z components.
+/// 
+/// Storing the components separately lets the bulk operations process many Vectors
+/// at once without shuffling components, which the compiler can auto-vectorise.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Hash,)]
+pub struct VectorBatch<Num,> {
+  /// The x components.
+  x: Vec<Num>,
+  /// The y components.
+  y: Vec<Num>,
+  /// The z components.
+  z: Vec<Num>,
+}
+
+impl<Num,> VectorBatch<Num,> {
+  /// Creates an empty VectorBatch.
+  #[inline]
+  pub const fn new() -> Self { Self { x: Vec::new(), y: Vec::new(), z: Vec::new(), } }
+  /// Creates an empty VectorBatch with space for `capacity` Vectors.
+  /// 
+  /// # Params
+  /// 
+  /// capacity --- The number of Vectors to allocate space for.  
+  pub fn with_capacity(capacity: usize,) -> Self {
+    Self {
+      x: Vec::with_capacity(capacity,),
+      y: Vec::with_capacity(capacity,),
+      z: Vec::with_capacity(capacity,),
+    }
+  }
+  /// Returns the number of Vectors in the batch.
+  #[inline]
+  pub fn len(&self,) -> usize { self.x.len() }
+  /// Returns `true` if the batch contains no Vectors.
+  #[inline]
+  pub fn is_empty(&self,) -> bool { self.x.is_empty() }
+  /// Removes all Vectors from the batch.
+  pub fn clear(&mut self,) {
+    self.x.clear();
+    self.y.clear();
+    self.z.clear();
+  }
+  /// Appends a Vector to the batch.
+  /// 
+  /// # Params
+  /// 
+  /// vector --- The Vector to append.  
+  pub fn push(&mut self, vector: Vector<Num,>,) {
+    self.x.push(vector.x,);
+    self.y.push(vector.y,);
+    self.z.push(vector.z,);
+  }
+  /// Returns the x, y and z component arrays.
+  #[inline]
+  pub fn as_slices(&self,) -> [&[Num]; 3] { [&self.x, &self.y, &self.z,] }
+  /// Returns the x, y and z component arrays mutably.
+  #[inline]
+  pub fn as_mut_slices(&mut self,) -> [&mut [Num]; 3] { [&mut self.x, &mut self.y, &mut self.z,] }
+}
+
+impl<Num: Clone,> VectorBatch<Num,> {
+  /// Returns the Vector at `index` or `None` if it is out of bounds.
+  /// 
+  /// # Params
+  /// 
+  /// index --- The index of the Vector.  
+  pub fn get(&self, index: usize,) -> Option<Vector<Num,>> {
+    Some(Vector::new(
+      self.x.get(index,)?.clone(),
+      self.y.get(index,)?.clone(),
+      self.z.get(index,)?.clone(),
+    ),)
+  }
+  /// Returns an iterator over copies of the Vectors in the batch.
+  pub fn iter(&self,) -> impl Iterator<Item = Vector<Num,>> + '_ {
+    self.x.iter().zip(&self.y,).zip(&self.z,)
+    .map(|((x, y,), z,),| Vector::new(x.clone(), y.clone(), z.clone(),),)
+  }
+}
+
+impl<Num: Number + Copy,> VectorBatch<Num,> {
+  /// Returns the dot product of each pair of Vectors.
+  /// 
+  /// # Panics
+  /// 
+  /// If the batches have different lengths.
+  /// 
+  /// # Params
+  /// 
+  /// rhs --- The Vectors to take the dot product with.  
+  pub fn dot(&self, rhs: &Self,) -> Vec<Num> {
+    assert_eq!(self.len(), rhs.len(), "VectorBatch lengths differ",);
+
+    (0..self.len()).map(|index,| {
+      (self.x[index] * rhs.x[index])
+      + (self.y[index] * rhs.y[index])
+      + (self.z[index] * rhs.z[index])
+    },).collect()
+  }
+  /// Returns the cross product of each pair of Vectors, as [Vector::cross].
+  /// 
+  /// # Panics
+  /// 
+  /// If the batches have different lengths.
+  /// 
+  /// # Params
+  /// 
+  /// rhs --- The right hand side of each cross product.  
+  pub fn cross(&self, rhs: &Self,) -> Self {
+    assert_eq!(self.len(), rhs.len(), "VectorBatch lengths differ",);
+
+    let len = self.len();
+    let (lhs_x, lhs_y, lhs_z,) = (&self.x[..len], &self.y[..len], &self.z[..len],);
+    let (rhs_x, rhs_y, rhs_z,) = (&rhs.x[..len], &rhs.y[..len], &rhs.z[..len],);
+
+    Self {
+      x: (0..len).map(|index,| (lhs_y[index] * rhs_z[index]) - (lhs_z[index] * rhs_y[index]),).collect(),
+      y: (0..len).map(|index,| (lhs_z[index] * rhs_x[index]) - (lhs_x[index] * rhs_z[index]),).collect(),
+      z: (0..len).map(|index,| (lhs_x[index] * rhs_y[index]) - (lhs_y[index] * rhs_x[index]),).collect(),
+    }
+  }
+  /// Combines each component with the matching component of `rhs` using `f`.
+  fn zip_components<F,>(&mut self, rhs: &Self, mut f: F,)
+    where F: FnMut(Num, Num,) -> Num, {
+    assert_eq!(self.len(), rhs.len(), "VectorBatch lengths differ",);
+
+    for (lhs, rhs,) in self.x.iter_mut().zip(&rhs.x,) { *lhs = f(*lhs, *rhs,) }
+    for (lhs, rhs,) in self.y.iter_mut().zip(&rhs.y,) { *lhs = f(*lhs, *rhs,) }
+    for (lhs, rhs,) in self.z.iter_mut().zip(&rhs.z,) { *lhs = f(*lhs, *rhs,) }
+  }
+  /// Applies `f` to each component array.
+  #[inline]
+  fn for_each_component<F,>(&mut self, mut f: F,)
+    where F: FnMut(&mut [Num],), {
+    f(&mut self.x,);
+    f(&mut self.y,);
+    f(&mut self.z,);
+  }
+}
+
+impl<Num: Trigonometry + Copy,> VectorBatch<Num,> {
+  /// Rotates every Vector in the batch.
+  /// 
+  /// The rotation is converted into a matrix once and then applied to every Vector.
+  /// 
+  /// # Params
+  /// 
+  /// rotation --- The rotation to apply.  
+  pub fn rotate(&mut self, rotation: &Rotation<Num,>,) {
+    let (sin, w,) = (rotation.angle / Num::from_isize(2,)).sin_cos();
+    let axis = rotation.axis.vector() * sin;
+    let (x, y, z,) = (axis.x, axis.y, axis.z,);
+    let two = Num::from_isize(2,);
+    let matrix = [
+      [Num::ONE - two * ((y * y) + (z * z)), two * ((x * y) - (w * z)), two * ((x * z) + (w * y)),],
+      [two * ((x * y) + (w * z)), Num::ONE - two * ((x * x) + (z * z)), two * ((y * z) - (w * x)),],
+      [two * ((x * z) - (w * y)), two * ((y * z) + (w * x)), Num::ONE - two * ((x * x) + (y * y)),],
+    ];
+    let len = self.len();
+    let (xs, ys, zs,) = (&mut self.x[..len], &mut self.y[..len], &mut self.z[..len],);
+
+    for index in 0..len {
+      let (x, y, z,) = (xs[index], ys[index], zs[index],);
+
+      xs[index] = (matrix[0][0] * x) + (matrix[0][1] * y) + (matrix[0][2] * z);
+      ys[index] = (matrix[1][0] * x) + (matrix[1][1] * y) + (matrix[1][2] * z);
+      zs[index] = (matrix[2][0] * x) + (matrix[2][1] * y) + (matrix[2][2] * z);
+    }
+  }
+}
+
+impl<Num: Sqrt + Copy,> VectorBatch<Num,> {
+  /// Scales every Vector in the batch to unit length.
+  /// 
+  /// Zero length Vectors are left unchanged.
+  pub fn normalize(&mut self,) {
+    let len = self.len();
+    let (xs, ys, zs,) = (&mut self.x[..len], &mut self.y[..len], &mut self.z[..len],);
+
+    for index in 0..len {
+      let magnituid = ((xs[index] * xs[index]) + (ys[index] * ys[index]) + (zs[index] * zs[index])).sqrt();
+
+      if magnituid > Num::ZERO {
+        xs[index] = xs[index] / magnituid;
+        ys[index] = ys[index] / magnituid;
+        zs[index] = zs[index] / magnituid;
+      }
+    }
+  }
+}
+
+impl<Num,> From<Vec<Vector<Num,>>> for VectorBatch<Num,> {
+  #[inline]
+  fn from(from: Vec<Vector<Num,>>,) -> Self { from.into_iter().collect() }
+}
+
+impl<Num,> From<VectorBatch<Num,>> for Vec<Vector<Num,>> {
+  fn from(from: VectorBatch<Num,>,) -> Self {
+    from.x.into_iter().zip(from.y,).zip(from.z,)
+    .map(|((x, y,), z,),| Vector::new(x, y, z,),)
+    .collect()
+  }
+}
+
+impl<Num,> FromIterator<Vector<Num,>> for VectorBatch<Num,> {
+  fn from_iter<I,>(iter: I,) -> Self
+    where I: IntoIterator<Item = Vector<Num,>>, {
+    let mut batch = Self::new();
+
+    batch.extend(iter,);
+    batch
+  }
+}
+
+impl<Num,> Extend<Vector<Num,>> for VectorBatch<Num,> {
+  fn extend<I,>(&mut self, iter: I,)
+    where I: IntoIterator<Item = Vector<Num,>>, {
+    let iter = iter.into_iter();
+    let (reserve, _,) = iter.size_hint();
+
+    self.x.reserve(reserve,);
+    self.y.reserve(reserve,);
+    self.z.reserve(reserve,);
+    for vector in iter { self.push(vector,) }
+  }
+}
+
+/// Adds `rhs` to every Vector in the batch.
+impl<Num: Number + Copy,> ops::AddAssign<Vector<Num,>> for VectorBatch<Num,> {
+  fn add_assign(&mut self, rhs: Vector<Num,>,) {
+    for x in &mut self.x { *x = *x + rhs.x }
+    for y in &mut self.y { *y = *y + rhs.y }
+    for z in &mut self.z { *z = *z + rhs.z }
+  }
+}
+
+/// Adds each pair of Vectors.
+/// 
+/// # Panics
+/// 
+/// If the batches have different lengths.
+impl<Num: Number + Copy,> ops::AddAssign<&VectorBatch<Num,>> for VectorBatch<Num,> {
+  #[inline]
+  fn add_assign(&mut self, rhs: &Self,) { self.zip_components(rhs, |lhs, rhs,| lhs + rhs,) }
+}
+
+/// Subtracts `rhs` from every Vector in the batch.
+impl<Num: Number + Copy,> ops::SubAssign<Vector<Num,>> for VectorBatch<Num,> {
+  #[inline]
+  fn sub_assign(&mut self, rhs: Vector<Num,>,) { *self += -rhs }
+}
+
+/// Subtracts each pair of Vectors.
+/// 
+/// # Panics
+/// 
+/// If the batches have different lengths.
+impl<Num: Number + Copy,> ops::SubAssign<&VectorBatch<Num,>> for VectorBatch<Num,> {
+  #[inline]
+  fn sub_assign(&mut self, rhs: &Self,) { self.zip_components(rhs, |lhs, rhs,| lhs - rhs,) }
+}
+
+/// Scales every Vector in the batch.
+impl<Num: Number + Copy,> ops::MulAssign<Num> for VectorBatch<Num,> {
+  fn mul_assign(&mut self, rhs: Num,) {
+    self.for_each_component(|components,| for component in components { *component = *component * rhs },);
+  }
+}
+
+/// Divides every Vector in the batch.
+impl<Num: Number + Copy,> ops::DivAssign<Num> for VectorBatch<Num,> {
+  fn div_assign(&mut self, rhs: Num,) {
+    self.for_each_component(|components,| for component in components { *component = *component / rhs },);
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_vector_batch() {
+    let vectors = vec![Vector::new(1, 2, 3,), Vector::new(-4, 0, 2,), Vector::new(0, 0, 7,),];
+    let mut batch = VectorBatch::from(vectors.clone(),);
+
+    assert_eq!(batch.len(), 3, "len failed",);
+    assert_eq!(batch.get(1,), Some(vectors[1],), "get failed",);
+    assert_eq!(batch.get(3,), None, "get out of bounds failed",);
+    assert_eq!(batch.as_slices()[2], &[3, 2, 7,], "as_slices failed",);
+    assert_eq!(Vec::from(batch.clone(),), vectors, "Convertion failed",);
+    assert_eq!(
+      batch.dot(&batch,),
+      vectors.iter().map(|&vec,| vec * vec,).collect::<Vec<_>>(),
+      "dot failed",
+    );
+
+    let other = vectors.iter().map(|&vec,| vec * 2 + Vector::X,).collect::<VectorBatch<_,>>();
+    assert_eq!(
+      Vec::from(batch.cross(&other,),),
+      vectors.iter().zip(other.iter(),).map(|(&lhs, rhs,),| Vector::cross(lhs, rhs,),).collect::<Vec<_>>(),
+      "cross failed",
+    );
+    assert_eq!(
+      Vec::from(VectorBatch::<i32,>::from(vec![Vector::X, Vector::Z,],).cross(&VectorBatch::from(vec![Vector::Z, Vector::X,],),),),
+      vec![Vector::NEG_Y, Vector::Y,],
+      "cross axes failed",
+    );
+
+    batch += Vector::new(1, 1, 1,);
+    batch *= 2;
+    assert_eq!(batch.get(0,), Some(Vector::new(4, 6, 8,)), "Scaling failed",);
+    batch -= &other;
+    assert_eq!(batch.get(1,), Some(Vector::new(1, 2, 2,)), "Subtraction failed",);
+    batch += &other;
+    batch /= 2;
+    batch -= Vector::new(1, 1, 1,);
+    assert_eq!(Vec::from(batch,), vectors, "Inverse operations failed",);
+  }
+  #[test]
+  fn test_vector_batch_rotate() {
+    let vectors = vec![Vector::new(1.0, 2.0, 3.0,), Vector::new(-4.0, 0.5, 2.0,), Vector::ZERO,];
+    let rotation = Rotation::new(Vector::new(1.0, 1.0, 0.0,).unit(), Degrees(30.0,),);
+    let mut batch = VectorBatch::from(vectors.clone(),);
+
+    batch.rotate(&rotation,);
+    for (index, vector,) in vectors.iter().enumerate() {
+      assert_abs_diff_eq!(batch.get(index,).unwrap(), vector.rotate(&rotation,), 1e-12, "rotate failed",);
+    }
+
+    batch.normalize();
+    for (index, vector,) in vectors.iter().enumerate().take(2,) {
+      assert_abs_diff_eq!(batch.get(index,).unwrap(), vector.rotate(&rotation,).unit().vector(), 1e-12, "normalize failed",);
+    }
+    assert_eq!(batch.get(2,), Some(Vector::ZERO,), "normalize zero failed",);
+  }
+}
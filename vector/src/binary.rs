@@ -0,0 +1,229 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use alloc::vec::Vec;
+#[cfg(feature = "fixed-point",)]
+use fixed_point::{Fixed32, Fixed64, Unsigned,};
+use core::{fmt, error::Error,};
+
+/// A value which can be encoded as compact little endian bytes.
+pub trait Encode {
+  /// Appends the encoding of this value to `bytes`.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The buffer to write to.  
+  fn encode(&self, bytes: &mut Vec<u8>,);
+  /// Returns the encoding of this value.
+  #[inline]
+  fn to_bytes(&self,) -> Vec<u8> {
+    let mut bytes = Vec::new();
+
+    self.encode(&mut bytes,);
+    bytes
+  }
+}
+
+/// A value which can be decoded from the bytes written by [Encode].
+pub trait Decode: Sized {
+  /// Decodes a value from the front of `bytes` and advances `bytes` past it.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The buffer to read from.  
+  fn decode(bytes: &mut &[u8],) -> Result<Self, DecodeError>;
+  /// Decodes a value which fills all of `bytes`.
+  /// 
+  /// # Params
+  /// 
+  /// bytes --- The encoded value.  
+  fn from_bytes(mut bytes: &[u8],) -> Result<Self, DecodeError> {
+    let value = Self::decode(&mut bytes,)?;
+
+    if bytes.is_empty() { Ok(value) } else { Err(DecodeError::TrailingBytes) }
+  }
+}
+
+/// Splits `N` bytes off the front of `bytes`.
+fn take<const N: usize,>(bytes: &mut &[u8],) -> Result<[u8; N], DecodeError> {
+  if bytes.len() < N { return Err(DecodeError::UnexpectedEnd) }
+
+  let (head, tail,) = bytes.split_at(N,);
+  let mut array = [0; N];
+
+  array.copy_from_slice(head,);
+  *bytes = tail;
+  Ok(array)
+}
+
+macro_rules! impl_primitive {
+  ($($num:ty,)*) => {
+    $(
+      impl Encode for $num {
+        #[inline]
+        fn encode(&self, bytes: &mut Vec<u8>,) { bytes.extend_from_slice(&self.to_le_bytes(),) }
+      }
+
+      impl Decode for $num {
+        #[inline]
+        fn decode(bytes: &mut &[u8],) -> Result<Self, DecodeError> { take(bytes,).map(Self::from_le_bytes,) }
+      }
+    )*
+  };
+}
+
+impl_primitive!(i8, i16, i32, i64, i128, f32, f64,);
+
+/// Encodes the raw bits of the value.
+/// 
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> Encode for Fixed32<Shift,> {
+  #[inline]
+  fn encode(&self, bytes: &mut Vec<u8>,) { self.to_bits().encode(bytes,) }
+}
+
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> Decode for Fixed32<Shift,> {
+  #[inline]
+  fn decode(bytes: &mut &[u8],) -> Result<Self, DecodeError> { i32::decode(bytes,).map(Self::from_bits,) }
+}
+
+/// Encodes the raw bits of the value.
+/// 
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> Encode for Fixed64<Shift,> {
+  #[inline]
+  fn encode(&self, bytes: &mut Vec<u8>,) { self.to_bits().encode(bytes,) }
+}
+
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> Decode for Fixed64<Shift,> {
+  #[inline]
+  fn decode(bytes: &mut &[u8],) -> Result<Self, DecodeError> { i64::decode(bytes,).map(Self::from_bits,) }
+}
+
+impl<Num: Encode,> Encode for Vector<Num,> {
+  fn encode(&self, bytes: &mut Vec<u8>,) {
+    self.x.encode(bytes,);
+    self.y.encode(bytes,);
+    self.z.encode(bytes,);
+  }
+}
+
+impl<Num: Decode,> Decode for Vector<Num,> {
+  fn decode(bytes: &mut &[u8],) -> Result<Self, DecodeError> {
+    Ok(Vector::new(Num::decode(bytes,)?, Num::decode(bytes,)?, Num::decode(bytes,)?,))
+  }
+}
+
+impl<Num: Encode,> Encode for Unit<Num,> {
+  #[inline]
+  fn encode(&self, bytes: &mut Vec<u8>,) { self.as_vector().encode(bytes,) }
+}
+
+/// Decoding fails if the [Unit] does not have a length of 1.
+impl<Num: Decode + Number + Clone,> Decode for Unit<Num,> {
+  fn decode(bytes: &mut &[u8],) -> Result<Self, DecodeError> {
+    Unit::checked(Vector::decode(bytes,)?,).ok_or(DecodeError::NotUnit,)
+  }
+}
+
+impl<Num: Encode,> Encode for Radians<Num,> {
+  #[inline]
+  fn encode(&self, bytes: &mut Vec<u8>,) { self.0.encode(bytes,) }
+}
+
+impl<Num: Decode,> Decode for Radians<Num,> {
+  #[inline]
+  fn decode(bytes: &mut &[u8],) -> Result<Self, DecodeError> { Num::decode(bytes,).map(Radians,) }
+}
+
+impl<Num: Encode,> Encode for Degrees<Num,> {
+  #[inline]
+  fn encode(&self, bytes: &mut Vec<u8>,) { self.0.encode(bytes,) }
+}
+
+impl<Num: Decode,> Decode for Degrees<Num,> {
+  #[inline]
+  fn decode(bytes: &mut &[u8],) -> Result<Self, DecodeError> { Num::decode(bytes,).map(Degrees,) }
+}
+
+impl<Num: Encode,> Encode for Rotation<Num,> {
+  fn encode(&self, bytes: &mut Vec<u8>,) {
+    self.axis.encode(bytes,);
+    self.angle.encode(bytes,);
+  }
+}
+
+impl<Num: Decode + Number + Clone,> Decode for Rotation<Num,> {
+  fn decode(bytes: &mut &[u8],) -> Result<Self, DecodeError> {
+    Ok(Rotation::new(Unit::decode(bytes,)?, Radians::decode(bytes,)?,))
+  }
+}
+
+/// The error returned when decoding a value fails.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum DecodeError {
+  /// The bytes ended part way through a value.
+  UnexpectedEnd,
+  /// Bytes remained after the value was decoded.
+  TrailingBytes,
+  /// A decoded [Unit] did not have a length of 1.
+  NotUnit,
+}
+
+impl fmt::Display for DecodeError {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.write_str(match self {
+      DecodeError::UnexpectedEnd => "the bytes ended part way through a value",
+      DecodeError::TrailingBytes => "bytes remained after the value was decoded",
+      DecodeError::NotUnit => "a Unit does not have a length of 1",
+    },)
+  }
+}
+
+impl Error for DecodeError {}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_binary() {
+    let vector = Vector::new(1.0f32, -2.5, 0.125,);
+    let bytes = vector.to_bytes();
+    assert_eq!(bytes.len(), 12, "Encoding length failed",);
+    assert_eq!(&bytes[..4], &[0x00, 0x00, 0x80, 0x3F,], "Encoding little endian failed",);
+    assert_eq!(Vector::from_bytes(&bytes,), Ok(vector), "Vector round trip failed",);
+
+    let rotation = Rotation::new(Unit::Y, Radians(0.75f64,),);
+    let decoded = Rotation::<f64>::from_bytes(&rotation.to_bytes(),).expect("Rotation round trip failed");
+    assert_eq!((decoded.axis, decoded.angle,), (rotation.axis, rotation.angle,), "Rotation round trip failed",);
+
+    let mut bytes = &Vector::new(1i16, 2, 3,).to_bytes()[..];
+    assert_eq!(i16::decode(&mut bytes,), Ok(1), "Streaming decode failed",);
+    assert_eq!(bytes.len(), 4, "Streaming advance failed",);
+
+    assert_eq!(Vector::<f32>::from_bytes(&vector.to_bytes()[..11],), Err(DecodeError::UnexpectedEnd), "Truncation accepted",);
+    assert_eq!(Vector::<i8>::from_bytes(&[1, 2, 3, 4,],), Err(DecodeError::TrailingBytes), "Trailing bytes accepted",);
+    assert_eq!(Unit::<f32>::from_bytes(&vector.to_bytes(),), Err(DecodeError::NotUnit), "Non unit accepted",);
+    assert_eq!(Unit::<f32>::from_bytes(&Vector::new(0.95f32, 0.0, 0.0,).to_bytes(),), Err(DecodeError::NotUnit), "Short unit accepted",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_binary_fixed() {
+    use fixed_point::consts::U16;
+
+    let vector = Vector::<Fixed32<U16>>::new(1.into(), (-2).into(), 0.5.into(),);
+    assert_eq!(&vector.to_bytes()[..4], &[0x00, 0x00, 0x01, 0x00,], "Fixed32 encoding failed",);
+    assert_eq!(Vector::from_bytes(&vector.to_bytes(),), Ok(vector), "Fixed32 round trip failed",);
+
+    let unit = Unit::<Fixed64<U16>>::Z;
+    assert_eq!(Unit::from_bytes(&unit.to_bytes(),), Ok(unit), "Fixed64 round trip failed",);
+  }
+}
@@ -0,0 +1,137 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::ops;
+
+/// An oriented plane segment in 3D space.
+/// 
+/// Where a [Vector] is a directed length a Bivector is a directed area, such as the
+/// parallelogram spanned by two Vectors. Each component is the area of the projection of
+/// the Bivector onto one of the coordinate planes.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,),)]
+pub struct Bivector<Num,> {
+  /// The area in the xy plane.
+  pub xy: Num,
+  /// The area in the yz plane.
+  pub yz: Num,
+  /// The area in the zx plane.
+  pub zx: Num,
+}
+
+impl<Num,> Bivector<Num,> {
+  /// Builds a new Bivector value.
+  #[inline]
+  pub const fn new(xy: Num, yz: Num, zx: Num,) -> Self { Self { xy, yz, zx, } }
+}
+
+impl<Num: Number,> Bivector<Num,> {
+  /// The Bivector with all components zero.
+  pub const ZERO: Self = Self::new(Num::ZERO, Num::ZERO, Num::ZERO,);
+  /// The unit Bivector of the xy plane, oriented from the x axis towards the y axis.
+  pub const XY: Self = Self::new(Num::ONE, Num::ZERO, Num::ZERO,);
+  /// The unit Bivector of the yz plane, oriented from the y axis towards the z axis.
+  pub const YZ: Self = Self::new(Num::ZERO, Num::ONE, Num::ZERO,);
+  /// The unit Bivector of the zx plane, oriented from the z axis towards the x axis.
+  pub const ZX: Self = Self::new(Num::ZERO, Num::ZERO, Num::ONE,);
+}
+
+impl<Num: Number + Clone,> Bivector<Num,> {
+  /// Returns the wedge product of two Vectors, the plane they span oriented from `lhs`
+  /// towards `rhs`.
+  /// 
+  /// The magnitude is the area of the parallelogram spanned by the Vectors.
+  /// 
+  /// # Params
+  /// 
+  /// lhs --- The first Vector.  
+  /// rhs --- The second Vector.  
+  pub fn wedge(lhs: Vector<Num,>, rhs: Vector<Num,>,) -> Self {
+    Self {
+      xy: (lhs.x.clone() * rhs.y.clone()) - (lhs.y.clone() * rhs.x.clone()),
+      yz: (lhs.y * rhs.z.clone()) - (lhs.z.clone() * rhs.y),
+      zx: (lhs.z * rhs.x) - (lhs.x * rhs.z),
+    }
+  }
+  /// Returns the Vector normal to this Bivector.
+  /// 
+  /// The plane appears counterclockwise when viewed from the tip of the normal, the
+  /// normal of `Bivector::wedge(a, b)` is the cross product of `a` and `b`.
+  #[inline]
+  pub fn normal(self,) -> Vector<Num,> { Vector::new(self.yz, self.zx, self.xy,) }
+  /// Returns the Bivector whose normal is `normal`.
+  /// 
+  /// # Params
+  /// 
+  /// normal --- The normal of the plane.  
+  #[inline]
+  pub fn from_normal(normal: Vector<Num,>,) -> Self { Self::new(normal.z, normal.x, normal.y,) }
+  /// Returns the square of the magnituid of this Bivector.
+  #[inline]
+  pub fn magnitude_squared(self,) -> Num { self.normal().magnitude_squared() }
+}
+
+impl<Num: Sqrt + Clone,> Bivector<Num,> {
+  /// Returns the magnituid of this Bivector, the area it spans.
+  #[inline]
+  pub fn magnituid(self,) -> Num { self.magnitude_squared().sqrt() }
+}
+
+impl<Num: Number + Clone,> ops::Neg for Bivector<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn neg(self,) -> Self::Output { Self::new(-self.xy, -self.yz, -self.zx,) }
+}
+
+impl<Num: Number + Clone,> ops::Add for Bivector<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn add(self, rhs: Self,) -> Self::Output { Self::new(self.xy + rhs.xy, self.yz + rhs.yz, self.zx + rhs.zx,) }
+}
+
+impl<Num: Number + Clone,> ops::Sub for Bivector<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn sub(self, rhs: Self,) -> Self::Output { Self::new(self.xy - rhs.xy, self.yz - rhs.yz, self.zx - rhs.zx,) }
+}
+
+impl<Num: Number + Clone,> ops::Mul<Num> for Bivector<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn mul(self, rhs: Num,) -> Self::Output {
+    Self::new(self.xy * rhs.clone(), self.yz * rhs.clone(), self.zx * rhs,)
+  }
+}
+
+impl<Num: Number + Clone,> ops::Div<Num> for Bivector<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn div(self, rhs: Num,) -> Self::Output {
+    Self::new(self.xy / rhs.clone(), self.yz / rhs.clone(), self.zx / rhs,)
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_bivector() {
+    assert_eq!(Bivector::wedge(Vector::X, Vector::Y,), Bivector::<i32>::XY, "Wedge failed 1",);
+    assert_eq!(Bivector::wedge(Vector::Y, Vector::Z,), Bivector::<i32>::YZ, "Wedge failed 2",);
+    assert_eq!(Bivector::wedge(Vector::Z, Vector::X,), Bivector::<i32>::ZX, "Wedge failed 3",);
+    assert_eq!(Bivector::wedge(Vector::Y, Vector::X,), -Bivector::<i32>::XY, "Wedge anticommutes failed",);
+
+    let (a, b,) = (Vector::new(1, 2, 3,), Vector::new(-4, 5, 6,),);
+    assert_eq!(Bivector::wedge(a, a,), Bivector::ZERO, "Wedge of parallel Vectors failed",);
+    assert_eq!(Bivector::wedge(a, b,).normal(), Vector::new(-3, -18, 13,), "Normal failed",);
+    assert_eq!(Bivector::from_normal(Bivector::wedge(a, b,).normal(),), Bivector::wedge(a, b,), "from_normal failed",);
+    assert_eq!(Bivector::wedge(Vector::X * 3.0, Vector::Y * 2.0,).magnituid(), 6.0, "Area failed",);
+  }
+}
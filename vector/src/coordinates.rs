@@ -0,0 +1,176 @@
+//! Spherical and cylindrical coordinate systems.
+//! 
+//! Both coordinate systems are right handed and use the z axis as their polar axis, in
+//! line with ISO 80000-2:
+//! 
+//! * the azimuth is measured in the xy plane from the positive x axis towards the
+//!   positive y axis and lies in `(-pi, pi]`.
+//! * the inclination is measured from the positive z axis and lies in `[0, pi]`.
+//! 
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+
+/// A point in spherical coordinates.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Spherical<Num,> {
+  /// The distance from the origin.
+  pub radius: Num,
+  /// The angle from the positive z axis.
+  pub inclination: Radians<Num,>,
+  /// The angle in the xy plane from the positive x axis.
+  pub azimuth: Radians<Num,>,
+}
+
+impl<Num,> Spherical<Num,> {
+  /// Creates a new [Spherical] value.
+  /// 
+  /// # Params
+  /// 
+  /// radius --- The distance from the origin.  
+  /// inclination --- The angle from the positive z axis.  
+  /// azimuth --- The angle in the xy plane from the positive x axis.  
+  #[inline]
+  pub const fn new(radius: Num, inclination: Radians<Num,>, azimuth: Radians<Num,>,) -> Self {
+    Self { radius, inclination, azimuth, }
+  }
+}
+
+/// A point in cylindrical coordinates.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Cylindrical<Num,> {
+  /// The distance from the z axis.
+  pub radius: Num,
+  /// The angle in the xy plane from the positive x axis.
+  pub azimuth: Radians<Num,>,
+  /// The distance along the z axis.
+  pub height: Num,
+}
+
+impl<Num,> Cylindrical<Num,> {
+  /// Creates a new [Cylindrical] value.
+  /// 
+  /// # Params
+  /// 
+  /// radius --- The distance from the z axis.  
+  /// azimuth --- The angle in the xy plane from the positive x axis.  
+  /// height --- The distance along the z axis.  
+  #[inline]
+  pub const fn new(radius: Num, azimuth: Radians<Num,>, height: Num,) -> Self {
+    Self { radius, azimuth, height, }
+  }
+}
+
+impl<Num,> From<Vector<Num,>> for Spherical<Num,>
+  where Num: Sqrt + Trigonometry + Clone, {
+  fn from(from: Vector<Num,>,) -> Self {
+    let planar = from.x.clone().hypot(from.y.clone(),);
+    let radius = from.clone().magnituid();
+    //`atan2` keeps the inclination accurate close to the poles.
+    let inclination = Radians(planar.atan2(from.z,),);
+    let azimuth = Radians(from.y.atan2(from.x,),);
+
+    Self { radius, inclination, azimuth, }
+  }
+}
+
+impl<Num,> From<Spherical<Num,>> for Vector<Num,>
+  where Num: Trigonometry + Clone, {
+  fn from(from: Spherical<Num,>,) -> Self {
+    let (sin_inc, cos_inc,) = from.inclination.sin_cos();
+    let (sin_az, cos_az,) = from.azimuth.sin_cos();
+    let planar = from.radius.clone() * sin_inc;
+
+    Vector::new(planar.clone() * cos_az, planar * sin_az, from.radius * cos_inc,)
+  }
+}
+
+impl<Num,> From<Vector<Num,>> for Cylindrical<Num,>
+  where Num: Trigonometry + Clone, {
+  fn from(from: Vector<Num,>,) -> Self {
+    let radius = from.x.clone().hypot(from.y.clone(),);
+    let azimuth = Radians(from.y.atan2(from.x,),);
+
+    Self { radius, azimuth, height: from.z, }
+  }
+}
+
+impl<Num,> From<Cylindrical<Num,>> for Vector<Num,>
+  where Num: Trigonometry + Clone, {
+  fn from(from: Cylindrical<Num,>,) -> Self {
+    let (sin, cos,) = from.azimuth.sin_cos();
+
+    Vector::new(from.radius.clone() * cos, from.radius * sin, from.height,)
+  }
+}
+
+impl<Num,> From<Spherical<Num,>> for Cylindrical<Num,>
+  where Num: Trigonometry + Clone, {
+  fn from(from: Spherical<Num,>,) -> Self {
+    let (sin, cos,) = from.inclination.sin_cos();
+
+    Self {
+      radius: from.radius.clone() * sin,
+      azimuth: from.azimuth,
+      height: from.radius * cos,
+    }
+  }
+}
+
+impl<Num,> From<Cylindrical<Num,>> for Spherical<Num,>
+  where Num: Trigonometry + Clone, {
+  fn from(from: Cylindrical<Num,>,) -> Self {
+    Self {
+      radius: from.radius.clone().hypot(from.height.clone(),),
+      inclination: Radians(from.radius.atan2(from.height,),),
+      azimuth: from.azimuth,
+    }
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+  use std::f64::consts::{PI, FRAC_PI_2, FRAC_PI_4,};
+
+  #[test]
+  fn test_spherical() {
+    let sph = Spherical::from(Vector::new(0.0, 2.0, 0.0,),);
+    assert_approx_eq!(sph.radius, 2.0, "Spherical radius failed",);
+    assert_approx_eq!(sph.inclination, Radians(FRAC_PI_2,), "Spherical inclination failed",);
+    assert_approx_eq!(sph.azimuth, Radians(FRAC_PI_2,), "Spherical azimuth failed",);
+
+    let sph = Spherical::from(Vector::new(0.0, 0.0, -3.0,),);
+    assert_approx_eq!(sph.inclination, Radians(PI,), "Spherical pole failed",);
+    assert_eq!(Spherical::from(Vector::<f64,>::ZERO,).radius, 0.0, "Spherical origin failed",);
+
+    let vec = Vector::new(1.0, -2.0, 3.0,);
+    assert_approx_eq!(Vector::from(Spherical::from(vec,),), vec, "Spherical round trip failed",);
+    assert_abs_diff_eq!(
+      Vector::from(Spherical::new(2.0, Radians(FRAC_PI_2,), Radians(PI,),),),
+      Vector::new(-2.0, 0.0, 0.0,), 1e-12,
+      "Spherical to Vector failed",
+    );
+  }
+  #[test]
+  fn test_cylindrical() {
+    let cyl = Cylindrical::from(Vector::new(1.0, 1.0, 5.0,),);
+    assert_approx_eq!(cyl.radius, 2.0f64.sqrt(), "Cylindrical radius failed",);
+    assert_approx_eq!(cyl.azimuth, Radians(FRAC_PI_4,), "Cylindrical azimuth failed",);
+    assert_eq!(cyl.height, 5.0, "Cylindrical height failed",);
+
+    let vec = Vector::new(-1.0, -2.0, 3.0,);
+    assert_approx_eq!(Vector::from(Cylindrical::from(vec,),), vec, "Cylindrical round trip failed",);
+
+    let sph = Spherical::from(vec,);
+    assert_approx_eq!(
+      Vector::from(Cylindrical::from(sph,),), vec,
+      "Spherical to Cylindrical failed",
+    );
+    assert_approx_eq!(
+      Vector::from(Spherical::from(Cylindrical::from(vec,),),), vec,
+      "Cylindrical to Spherical failed",
+    );
+  }
+}
@@ -0,0 +1,470 @@
+//! Parametric curves through [Vector] control points.
+//! 
+//! Every curve is parameterised over `t` in `[0, 1]`.
+//! 
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+
+/// A trait for curves parameterised over `[0, 1]`.
+pub trait Curve<Num,>
+  where Num: Number + Clone, {
+  /// Returns the position on the curve at `t`.
+  /// 
+  /// # Params
+  /// 
+  /// t --- The parameter of the position.  
+  fn evaluate(&self, t: Num,) -> Vector<Num,>;
+  /// Returns the derivative of the curve with respect to `t`.
+  /// 
+  /// # Params
+  /// 
+  /// t --- The parameter of the derivative.  
+  fn derivative(&self, t: Num,) -> Vector<Num,>;
+  /// Approximates the length of the curve by summing `samples` chords.
+  /// 
+  /// # Params
+  /// 
+  /// samples --- The number of chords to sum.  
+  fn arc_length(&self, samples: usize,) -> Num
+    where Num: Sqrt, {
+    let samples = samples.max(1,);
+    let mut last = self.evaluate(Num::ZERO,);
+    let mut length = Num::ZERO;
+
+    for index in 1..=samples {
+      let next = self.evaluate(parameter(index, samples,),);
+
+      length = length + Vector::distance(last, next.clone(),);
+      last = next;
+    }
+
+    length
+  }
+  /// Returns the parameter at which the curve has travelled `distance` along its length.
+  /// 
+  /// The length is approximated by `samples` chords and the result is clamped to
+  /// `[0, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// distance --- The distance along the curve.  
+  /// samples --- The number of chords to approximate the curve with.  
+  fn parameter_at_distance(&self, distance: Num, samples: usize,) -> Num
+    where Num: Sqrt, {
+    let samples = samples.max(1,);
+    let mut last = self.evaluate(Num::ZERO,);
+    let mut travelled = Num::ZERO;
+
+    if distance <= Num::ZERO { return Num::ZERO }
+
+    for index in 1..=samples {
+      let next = self.evaluate(parameter(index, samples,),);
+      let chord = Vector::distance(last, next.clone(),);
+
+      if travelled.clone() + chord.clone() >= distance {
+        //Interpolate along the chord which contains `distance`.
+        let fraction = if chord == Num::ZERO { Num::ZERO }
+          else { (distance - travelled) / chord };
+
+        return parameter::<Num,>(index - 1, samples,) + (fraction / Num::from_isize(samples as isize,))
+      }
+
+      travelled = travelled + chord;
+      last = next;
+    }
+
+    Num::ONE
+  }
+  /// Returns the parameter of the position on the curve closest to `point`.
+  /// 
+  /// The curve is first sampled at `samples` evenly spaced parameters and the closest
+  /// sample is then refined using `iterations` steps of a ternary search.
+  /// 
+  /// # Params
+  /// 
+  /// point --- The position to find the closest position on the curve to.  
+  /// samples --- The number of evenly spaced samples to take.  
+  /// iterations --- The number of refinement steps to take.  
+  fn closest_parameter(&self, point: Vector<Num,>, samples: usize, iterations: usize,) -> Num {
+    let samples = samples.max(1,);
+    let distance = |t: Num,| Vector::distance_squared(self.evaluate(t,), point.clone(),);
+    let closest = (0..=samples)
+      .map(|index,| (index, distance(parameter(index, samples,),),),)
+      .fold(None, |closest: Option<(usize, Num,)>, (index, dist,),| match closest {
+        Some((_, ref best,)) if *best <= dist => closest,
+        _ => Some((index, dist,)),
+      },)
+      .map_or(0, |(index, _,),| index,);
+    let mut low = parameter::<Num,>(closest.saturating_sub(1,), samples,);
+    let mut high = parameter::<Num,>((closest + 1).min(samples,), samples,);
+    let three = Num::from_isize(3,);
+
+    for _ in 0..iterations {
+      let third = (high.clone() - low.clone()) / three.clone();
+      let left = low.clone() + third.clone();
+      let right = high.clone() - third;
+
+      if distance(left.clone(),) <= distance(right.clone(),) { high = right }
+      else { low = left }
+    }
+
+    (low + high) / Num::from_isize(2,)
+  }
+}
+
+/// Returns the parameter of sample `index` out of `samples`.
+#[inline]
+fn parameter<Num: Number,>(index: usize, samples: usize,) -> Num {
+  Num::from_isize(index as isize,) / Num::from_isize(samples as isize,)
+}
+
+/// A quadratic Bezier curve.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct QuadraticBezier<Num,> {
+  /// The start of the curve.
+  pub p0: Vector<Num,>,
+  /// The control point.
+  pub p1: Vector<Num,>,
+  /// The end of the curve.
+  pub p2: Vector<Num,>,
+}
+
+impl<Num,> QuadraticBezier<Num,> {
+  /// Creates a new [QuadraticBezier] value.
+  /// 
+  /// # Params
+  /// 
+  /// p0 --- The start of the curve.  
+  /// p1 --- The control point.  
+  /// p2 --- The end of the curve.  
+  #[inline]
+  pub const fn new(p0: Vector<Num,>, p1: Vector<Num,>, p2: Vector<Num,>,) -> Self {
+    Self { p0, p1, p2, }
+  }
+}
+
+impl<Num: Number + Clone,> QuadraticBezier<Num,> {
+  /// Splits the curve at `t` into two curves which together trace the same path.
+  /// 
+  /// # Params
+  /// 
+  /// t --- The parameter to split at.  
+  pub fn split(self, t: Num,) -> (Self, Self,) {
+    let q0 = self.p0.clone().lerp(self.p1.clone(), t.clone(),);
+    let q1 = self.p1.lerp(self.p2.clone(), t.clone(),);
+    let mid = q0.clone().lerp(q1.clone(), t,);
+
+    (Self::new(self.p0, q0, mid.clone(),), Self::new(mid, q1, self.p2,),)
+  }
+}
+
+impl<Num: Number + Clone,> Curve<Num,> for QuadraticBezier<Num,> {
+  fn evaluate(&self, t: Num,) -> Vector<Num,> {
+    let u = Num::ONE - t.clone();
+
+    (self.p0.clone() * (u.clone() * u.clone()))
+    + (self.p1.clone() * (Num::from_isize(2,) * u * t.clone()))
+    + (self.p2.clone() * (t.clone() * t))
+  }
+  fn derivative(&self, t: Num,) -> Vector<Num,> {
+    let u = Num::ONE - t.clone();
+    let two = Num::from_isize(2,);
+
+    ((self.p1.clone() - self.p0.clone()) * (two.clone() * u))
+    + ((self.p2.clone() - self.p1.clone()) * (two * t))
+  }
+}
+
+/// A cubic Bezier curve.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct CubicBezier<Num,> {
+  /// The start of the curve.
+  pub p0: Vector<Num,>,
+  /// The first control point.
+  pub p1: Vector<Num,>,
+  /// The second control point.
+  pub p2: Vector<Num,>,
+  /// The end of the curve.
+  pub p3: Vector<Num,>,
+}
+
+impl<Num,> CubicBezier<Num,> {
+  /// Creates a new [CubicBezier] value.
+  /// 
+  /// # Params
+  /// 
+  /// p0 --- The start of the curve.  
+  /// p1 --- The first control point.  
+  /// p2 --- The second control point.  
+  /// p3 --- The end of the curve.  
+  #[inline]
+  pub const fn new(p0: Vector<Num,>, p1: Vector<Num,>, p2: Vector<Num,>, p3: Vector<Num,>,) -> Self {
+    Self { p0, p1, p2, p3, }
+  }
+}
+
+impl<Num: Number + Clone,> CubicBezier<Num,> {
+  /// Splits the curve at `t` into two curves which together trace the same path.
+  /// 
+  /// # Params
+  /// 
+  /// t --- The parameter to split at.  
+  pub fn split(self, t: Num,) -> (Self, Self,) {
+    let q0 = self.p0.clone().lerp(self.p1.clone(), t.clone(),);
+    let q1 = self.p1.lerp(self.p2.clone(), t.clone(),);
+    let q2 = self.p2.lerp(self.p3.clone(), t.clone(),);
+    let r0 = q0.clone().lerp(q1.clone(), t.clone(),);
+    let r1 = q1.lerp(q2.clone(), t.clone(),);
+    let mid = r0.clone().lerp(r1.clone(), t,);
+
+    (Self::new(self.p0, q0, r0, mid.clone(),), Self::new(mid, r1, q2, self.p3,),)
+  }
+}
+
+impl<Num: Number + Clone,> Curve<Num,> for CubicBezier<Num,> {
+  fn evaluate(&self, t: Num,) -> Vector<Num,> {
+    let u = Num::ONE - t.clone();
+    let three = Num::from_isize(3,);
+
+    (self.p0.clone() * (u.clone() * u.clone() * u.clone()))
+    + (self.p1.clone() * (three.clone() * u.clone() * u.clone() * t.clone()))
+    + (self.p2.clone() * (three * u * t.clone() * t.clone()))
+    + (self.p3.clone() * (t.clone() * t.clone() * t))
+  }
+  fn derivative(&self, t: Num,) -> Vector<Num,> {
+    let u = Num::ONE - t.clone();
+    let three = Num::from_isize(3,);
+
+    ((self.p1.clone() - self.p0.clone()) * (three.clone() * u.clone() * u.clone()))
+    + ((self.p2.clone() - self.p1.clone()) * (Num::from_isize(6,) * u * t.clone()))
+    + ((self.p3.clone() - self.p2.clone()) * (three * t.clone() * t))
+  }
+}
+
+/// A cubic Hermite curve defined by its end points and the tangents at them.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Hermite<Num,> {
+  /// The start of the curve.
+  pub start: Vector<Num,>,
+  /// The tangent at the start of the curve.
+  pub start_tangent: Vector<Num,>,
+  /// The end of the curve.
+  pub end: Vector<Num,>,
+  /// The tangent at the end of the curve.
+  pub end_tangent: Vector<Num,>,
+}
+
+impl<Num,> Hermite<Num,> {
+  /// Creates a new [Hermite] value.
+  /// 
+  /// # Params
+  /// 
+  /// start --- The start of the curve.  
+  /// start_tangent --- The tangent at the start of the curve.  
+  /// end --- The end of the curve.  
+  /// end_tangent --- The tangent at the end of the curve.  
+  #[inline]
+  pub const fn new(
+    start: Vector<Num,>, start_tangent: Vector<Num,>,
+    end: Vector<Num,>, end_tangent: Vector<Num,>,
+  ) -> Self { Self { start, start_tangent, end, end_tangent, } }
+}
+
+impl<Num: Number + Clone,> Hermite<Num,> {
+  /// Splits the curve at `t` into two curves which together trace the same path.
+  /// 
+  /// # Params
+  /// 
+  /// t --- The parameter to split at.  
+  pub fn split(self, t: Num,) -> (Self, Self,) {
+    let (left, right,) = CubicBezier::from(self,).split(t,);
+
+    (left.into(), right.into(),)
+  }
+}
+
+impl<Num: Number + Clone,> Curve<Num,> for Hermite<Num,> {
+  fn evaluate(&self, t: Num,) -> Vector<Num,> {
+    let t2 = t.clone() * t.clone();
+    let t3 = t2.clone() * t.clone();
+    let two = Num::from_isize(2,);
+    let three = Num::from_isize(3,);
+
+    (self.start.clone() * ((two.clone() * t3.clone()) - (three.clone() * t2.clone()) + Num::ONE))
+    + (self.start_tangent.clone() * (t3.clone() - (two.clone() * t2.clone()) + t))
+    + (self.end.clone() * ((three * t2.clone()) - (two * t3.clone())))
+    + (self.end_tangent.clone() * (t3 - t2))
+  }
+  fn derivative(&self, t: Num,) -> Vector<Num,> {
+    let t2 = t.clone() * t.clone();
+    let six = Num::from_isize(6,);
+    let three = Num::from_isize(3,);
+    let start = (six.clone() * t2.clone()) - (six * t.clone());
+
+    (self.start.clone() * start.clone())
+    + (self.start_tangent.clone()
+      * ((three.clone() * t2.clone()) - (Num::from_isize(4,) * t.clone()) + Num::ONE))
+    - (self.end.clone() * start)
+    + (self.end_tangent.clone() * ((three * t2) - (Num::from_isize(2,) * t)))
+  }
+}
+
+/// A uniform Catmull-Rom segment which passes through `p1` and `p2`.
+/// 
+/// The outer points `p0` and `p3` only shape the tangents so consecutive segments
+/// sharing three points join smoothly.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct CatmullRom<Num,> {
+  /// The point before the segment.
+  pub p0: Vector<Num,>,
+  /// The start of the segment.
+  pub p1: Vector<Num,>,
+  /// The end of the segment.
+  pub p2: Vector<Num,>,
+  /// The point after the segment.
+  pub p3: Vector<Num,>,
+}
+
+impl<Num,> CatmullRom<Num,> {
+  /// Creates a new [CatmullRom] value.
+  /// 
+  /// # Params
+  /// 
+  /// p0 --- The point before the segment.  
+  /// p1 --- The start of the segment.  
+  /// p2 --- The end of the segment.  
+  /// p3 --- The point after the segment.  
+  #[inline]
+  pub const fn new(p0: Vector<Num,>, p1: Vector<Num,>, p2: Vector<Num,>, p3: Vector<Num,>,) -> Self {
+    Self { p0, p1, p2, p3, }
+  }
+}
+
+impl<Num: Number + Clone,> Curve<Num,> for CatmullRom<Num,> {
+  #[inline]
+  fn evaluate(&self, t: Num,) -> Vector<Num,> { Hermite::from(self.clone(),).evaluate(t,) }
+  #[inline]
+  fn derivative(&self, t: Num,) -> Vector<Num,> { Hermite::from(self.clone(),).derivative(t,) }
+}
+
+impl<Num: Number + Clone,> From<QuadraticBezier<Num,>> for CubicBezier<Num,> {
+  fn from(from: QuadraticBezier<Num,>,) -> Self {
+    let third = Num::ONE / Num::from_isize(3,);
+    let p1 = from.p0.clone() + ((from.p1.clone() - from.p0.clone()) * (third.clone() * Num::from_isize(2,)));
+    let p2 = from.p2.clone() + ((from.p1 - from.p2.clone()) * (third * Num::from_isize(2,)));
+
+    Self::new(from.p0, p1, p2, from.p2,)
+  }
+}
+
+impl<Num: Number + Clone,> From<Hermite<Num,>> for CubicBezier<Num,> {
+  fn from(from: Hermite<Num,>,) -> Self {
+    let three = Num::from_isize(3,);
+    let p1 = from.start.clone() + (from.start_tangent / three.clone());
+    let p2 = from.end.clone() - (from.end_tangent / three);
+
+    Self::new(from.start, p1, p2, from.end,)
+  }
+}
+
+impl<Num: Number + Clone,> From<CubicBezier<Num,>> for Hermite<Num,> {
+  fn from(from: CubicBezier<Num,>,) -> Self {
+    let three = Num::from_isize(3,);
+    let start_tangent = (from.p1 - from.p0.clone()) * three.clone();
+    let end_tangent = (from.p3.clone() - from.p2) * three;
+
+    Self::new(from.p0, start_tangent, from.p3, end_tangent,)
+  }
+}
+
+impl<Num: Number + Clone,> From<CatmullRom<Num,>> for Hermite<Num,> {
+  fn from(from: CatmullRom<Num,>,) -> Self {
+    let two = Num::from_isize(2,);
+    let start_tangent = (from.p2.clone() - from.p0) / two.clone();
+    let end_tangent = (from.p3 - from.p1.clone()) / two;
+
+    Self::new(from.p1, start_tangent, from.p2, end_tangent,)
+  }
+}
+
+impl<Num: Number + Clone,> From<CatmullRom<Num,>> for CubicBezier<Num,> {
+  #[inline]
+  fn from(from: CatmullRom<Num,>,) -> Self { Hermite::from(from,).into() }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_bezier() {
+    let quad = QuadraticBezier::new(Vector::new(0.0, 0.0, 0.0,), Vector::new(1.0, 2.0, 0.0,), Vector::new(2.0, 0.0, 0.0,),);
+    assert_eq!(quad.evaluate(0.5,), Vector::new(1.0, 1.0, 0.0,), "Quadratic evaluate failed",);
+    assert_eq!(quad.derivative(0.5,), Vector::new(2.0, 0.0, 0.0,), "Quadratic derivative failed",);
+
+    let cubic = CubicBezier::from(quad,);
+    assert_approx_eq!(cubic.evaluate(0.25,), quad.evaluate(0.25,), "Degree elevation failed",);
+    assert_approx_eq!(cubic.derivative(0.75,), quad.derivative(0.75,), "Cubic derivative failed",);
+
+    let (left, right,) = cubic.split(0.5,);
+    assert_approx_eq!(left.evaluate(0.5,), cubic.evaluate(0.25,), "Cubic split left failed",);
+    assert_approx_eq!(right.evaluate(0.5,), cubic.evaluate(0.75,), "Cubic split right failed",);
+
+    let (left, right,) = quad.split(0.25,);
+    assert_eq!(left.p2, quad.evaluate(0.25,), "Quadratic split failed",);
+    assert_eq!(right.evaluate(1.0,), quad.p2, "Quadratic split end failed",);
+  }
+  #[test]
+  fn test_hermite() {
+    let catmull = CatmullRom::new(
+      Vector::new(-1.0, 0.0, 0.0,), Vector::new(0.0, 0.0, 0.0,),
+      Vector::new(1.0, 1.0, 0.0,), Vector::new(2.0, 1.0, 0.0,),
+    );
+    assert_eq!(catmull.evaluate(0.0,), catmull.p1, "CatmullRom start failed",);
+    assert_eq!(catmull.evaluate(1.0,), catmull.p2, "CatmullRom end failed",);
+    assert_eq!(catmull.derivative(0.0,), Vector::new(1.0, 0.5, 0.0,), "CatmullRom tangent failed",);
+
+    let hermite = Hermite::from(catmull,);
+    let bezier = CubicBezier::from(catmull,);
+    assert_approx_eq!(bezier.evaluate(0.3,), hermite.evaluate(0.3,), "Hermite convertion failed",);
+    assert_approx_eq!(bezier.derivative(0.3,), hermite.derivative(0.3,), "Hermite derivative failed",);
+
+    let (left, right,) = hermite.split(0.5,);
+    assert_approx_eq!(left.evaluate(0.5,), hermite.evaluate(0.25,), "Hermite split left failed",);
+    assert_approx_eq!(right.evaluate(0.5,), hermite.evaluate(0.75,), "Hermite split right failed",);
+  }
+  #[test]
+  fn test_curve() {
+    let line = QuadraticBezier::new(Vector::new(0.0, 0.0, 0.0,), Vector::new(0.0, 0.0, 1.0,), Vector::new(0.0, 0.0, 4.0,),);
+    assert_approx_eq!(line.arc_length(64,), 4.0, "arc_length failed",);
+    assert_abs_diff_eq!(line.evaluate(line.parameter_at_distance(1.0, 256,),), Vector::new(0.0, 0.0, 1.0,), 1e-3, "parameter_at_distance failed",);
+    assert_eq!(line.parameter_at_distance(5.0, 16,), 1.0, "parameter_at_distance overrun failed",);
+    assert_eq!(line.parameter_at_distance(-1.0, 16,), 0.0, "parameter_at_distance underrun failed",);
+
+    let arc = CubicBezier::new(
+      Vector::new(0.0, 0.0, 0.0,), Vector::new(0.0, 1.0, 0.0,),
+      Vector::new(1.0, 1.0, 0.0,), Vector::new(1.0, 0.0, 0.0,),
+    );
+    let t = arc.closest_parameter(Vector::new(0.5, 2.0, 0.0,), 16, 32,);
+    assert_abs_diff_eq!(t, 0.5, 1e-6, "closest_parameter failed",);
+    assert_abs_diff_eq!(arc.closest_parameter(Vector::new(-1.0, -1.0, 0.0,), 16, 32,), 0.0, 1e-6, "closest_parameter start failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_curve_fixed() {
+    use fixed_point::{Fixed32, consts::U16,};
+
+    let fixed = |x: i32, y: i32, z: i32,| Vector::<Fixed32<U16>>::new(x.into(), y.into(), z.into(),);
+    let arc = CubicBezier::new(fixed(0, 0, 0,), fixed(0, 1, 0,), fixed(1, 1, 0,), fixed(1, 0, 0,),);
+    assert_eq!(arc.evaluate(Fixed32::from(0.5,),), Vector::new(0.5, 0.75, 0.0,).map(Fixed32::from,), "Fixed32 evaluate failed",);
+    assert_eq!(arc.derivative(Fixed32::from(0.5,),), fixed(1, 0, 0,) * Fixed32::from(1.5,), "Fixed32 derivative failed",);
+
+    let t = arc.closest_parameter(fixed(0, 2, 0,) + fixed(1, 0, 0,) / Fixed32::from(2,), 16, 16,);
+    assert_abs_diff_eq!(t, Fixed32::from(0.5,), Fixed32::from(0.01,), "Fixed32 closest_parameter failed",);
+
+    let line = QuadraticBezier::new(fixed(0, 0, 0,), fixed(0, 0, 1,), fixed(0, 0, 4,),);
+    assert_abs_diff_eq!(line.arc_length(16,), Fixed32::from(4,), Fixed32::from(0.01,), "Fixed32 arc_length failed",);
+  }
+}
@@ -0,0 +1,487 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+
+/// The most sweeps the Jacobi methods perform before giving up on convergence.
+const SWEEPS: usize = 32;
+/// The pairs of indices visited by a Jacobi sweep.
+const PAIRS: [(usize, usize,); 3] = [(0, 1,), (0, 2,), (1, 2,),];
+
+/// The LU decomposition of a [Matrix] with partial pivoting.
+/// 
+/// The rows of the Matrix are permuted so that `P * A = L * U`, where `L` is lower
+/// triangular with a unit diagonal and `U` is upper triangular.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Lu<Num,> {
+  /// `L` below the diagonal and `U` on and above it.
+  lu: Matrix<Num,>,
+  /// The row of the original Matrix at each row of the decomposition.
+  permutation: [usize; 3],
+  /// `true` if an odd number of rows were swapped.
+  odd: bool,
+}
+
+impl<Num: Number + Clone,> Lu<Num,> {
+  /// Decomposes `matrix`.
+  /// 
+  /// Each column is pivoted on its largest remaining element to limit rounding error.
+  /// 
+  /// # Params
+  /// 
+  /// matrix --- The Matrix to decompose.  
+  pub fn new(matrix: Matrix<Num,>,) -> Self {
+    let mut lu = matrix;
+    let mut permutation = [0, 1, 2,];
+    let mut odd = false;
+
+    for column in 0..3 {
+      let mut pivot = column;
+
+      for row in column + 1..3 {
+        if abs(lu[row][column].clone(),) > abs(lu[pivot][column].clone(),) { pivot = row }
+      }
+
+      if pivot != column {
+        lu.rows.swap(pivot, column,);
+        permutation.swap(pivot, column,);
+        odd = !odd;
+      }
+
+      //A zero pivot means the rest of the column is already zero.
+      if lu[column][column] == Num::ZERO { continue }
+
+      for row in column + 1..3 {
+        let factor = lu[row][column].clone() / lu[column][column].clone();
+
+        for index in column + 1..3 {
+          lu[row][index] = lu[row][index].clone() - (factor.clone() * lu[column][index].clone());
+        }
+
+        lu[row][column] = factor;
+      }
+    }
+
+    Self { lu, permutation, odd, }
+  }
+  /// Returns the lower triangular factor `L`.
+  pub fn lower(&self,) -> Matrix<Num,> {
+    let mut lower = Matrix::IDENTITY;
+
+    for row in 1..3 {
+      for column in 0..row { lower[row][column] = self.lu[row][column].clone() }
+    }
+
+    lower
+  }
+  /// Returns the upper triangular factor `U`.
+  pub fn upper(&self,) -> Matrix<Num,> {
+    let mut upper = Matrix::ZERO;
+
+    for row in 0..3 {
+      for column in row..3 { upper[row][column] = self.lu[row][column].clone() }
+    }
+
+    upper
+  }
+  /// Returns the row of the original Matrix at each row of `P * A`.
+  #[inline]
+  pub fn permutation(&self,) -> [usize; 3] { self.permutation }
+  /// Returns the determinant of the decomposed Matrix.
+  pub fn determinant(&self,) -> Num {
+    let determinant = self.lu[0][0].clone() * self.lu[1][1].clone() * self.lu[2][2].clone();
+
+    if self.odd { -determinant } else { determinant }
+  }
+  /// Solves `A * x = rhs` for `x`.
+  /// 
+  /// Returns `None` if the decomposed Matrix is singular.
+  /// 
+  /// # Params
+  /// 
+  /// rhs --- The right hand side of the system.  
+  pub fn solve(&self, rhs: Vector<Num,>,) -> Option<Vector<Num,>> {
+    let mut solution = Vector::<Num,>::ZERO;
+
+    //Forward substitution with `L`.
+    for row in 0..3 {
+      let mut sum = rhs[self.permutation[row]].clone();
+
+      for column in 0..row { sum = sum - (self.lu[row][column].clone() * solution[column].clone()) }
+      solution[row] = sum;
+    }
+
+    back_substitute(&self.lu, solution,)
+  }
+  /// Returns the inverse of the decomposed Matrix.
+  /// 
+  /// Returns `None` if the decomposed Matrix is singular.
+  pub fn inverse(&self,) -> Option<Matrix<Num,>> {
+    Some(Matrix::from_columns([
+      self.solve(Vector::X,)?,
+      self.solve(Vector::Y,)?,
+      self.solve(Vector::Z,)?,
+    ],),)
+  }
+}
+
+/// The QR decomposition of a [Matrix] by Householder reflections.
+/// 
+/// `A = Q * R`, where `Q` is orthogonal and `R` is upper triangular.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Qr<Num,> {
+  /// The orthogonal factor.
+  pub q: Matrix<Num,>,
+  /// The upper triangular factor.
+  pub r: Matrix<Num,>,
+}
+
+impl<Num: Sqrt + Clone,> Qr<Num,> {
+  /// Decomposes `matrix`.
+  /// 
+  /// # Params
+  /// 
+  /// matrix --- The Matrix to decompose.  
+  pub fn new(matrix: Matrix<Num,>,) -> Self {
+    let mut q = Matrix::IDENTITY;
+    let mut r = matrix;
+
+    for column in 0..2 {
+      let mut normal = Vector::ZERO;
+
+      for row in column..3 { normal[row] = r[row][column].clone() }
+
+      let length = normal.clone().magnituid();
+
+      if length == Num::ZERO { continue }
+
+      //Reflecting onto the axis with the opposite sign avoids cancellation.
+      let diagonal = if normal[column] < Num::ZERO { length } else { -length };
+
+      normal[column] = normal[column].clone() - diagonal.clone();
+
+      let scale = Num::from_isize(2,) / normal.clone().magnitude_squared();
+      let reflection = Matrix::IDENTITY - (Matrix::outer(normal.clone(), normal,) * scale);
+
+      r = reflection.clone() * r;
+      q = q * reflection;
+      //Store the exact values which the reflection only approximates.
+      r[column][column] = diagonal;
+      for row in column + 1..3 { r[row][column] = Num::ZERO }
+    }
+
+    Self { q, r, }
+  }
+  /// Solves `A * x = rhs` for `x`.
+  /// 
+  /// Returns `None` if the decomposed Matrix is singular.
+  /// 
+  /// # Params
+  /// 
+  /// rhs --- The right hand side of the system.  
+  #[inline]
+  pub fn solve(&self, rhs: Vector<Num,>,) -> Option<Vector<Num,>> {
+    back_substitute(&self.r, self.q.clone().transpose() * rhs,)
+  }
+}
+
+/// The eigendecomposition of a symmetric [Matrix] by the Jacobi eigenvalue method.
+/// 
+/// `A * vectors[i] = values[i] * vectors[i]` with the eigenvalues in ascending order and
+/// the eigenvectors orthonormal.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct SymmetricEigen<Num,> {
+  /// The eigenvalues in ascending order.
+  pub values: [Num; 3],
+  /// The eigenvector of each eigenvalue.
+  pub vectors: [Vector<Num,>; 3],
+}
+
+impl<Num: Sqrt + Clone,> SymmetricEigen<Num,> {
+  /// Decomposes `matrix`.
+  /// 
+  /// Only the upper triangle of `matrix` is used, the lower triangle is assumed to mirror
+  /// it.
+  /// 
+  /// # Params
+  /// 
+  /// matrix --- The symmetric Matrix to decompose.  
+  pub fn new(matrix: Matrix<Num,>,) -> Self {
+    let mut matrix = symmetric(matrix,);
+    let mut vectors = Matrix::IDENTITY;
+
+    for _ in 0..SWEEPS {
+      let previous = matrix.clone();
+
+      for &(p, q,) in PAIRS.iter() {
+        if matrix[p][q] == Num::ZERO { continue }
+
+        let rotation = rotation(p, q, jacobi(matrix[p][p].clone(), matrix[q][q].clone(), matrix[p][q].clone(),),);
+
+        matrix = symmetric(rotation.clone().transpose() * matrix * rotation.clone(),);
+        //The rotation is chosen to zero this element, store it exactly.
+        matrix[p][q] = Num::ZERO;
+        matrix[q][p] = Num::ZERO;
+        vectors = vectors * rotation;
+      }
+
+      if matrix == previous { break }
+    }
+
+    let mut values = [matrix[0][0].clone(), matrix[1][1].clone(), matrix[2][2].clone(),];
+    let mut vectors = vectors.columns();
+
+    sort(&mut values, &mut vectors, |lhs, rhs,| lhs < rhs,);
+    Self { values, vectors, }
+  }
+}
+
+/// The singular value decomposition of a [Matrix] by the one sided Jacobi method.
+/// 
+/// `A = U * S * V^T`, where `U` and `V` are orthogonal and `S` is the diagonal Matrix of
+/// the singular values.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Svd<Num,> {
+  /// The left singular vectors as columns.
+  pub u: Matrix<Num,>,
+  /// The singular values in descending order, never negative.
+  pub values: Vector<Num,>,
+  /// The right singular vectors as columns.
+  pub v: Matrix<Num,>,
+}
+
+impl<Num: Sqrt + Clone,> Svd<Num,> {
+  /// Decomposes `matrix`.
+  /// 
+  /// # Params
+  /// 
+  /// matrix --- The Matrix to decompose.  
+  pub fn new(matrix: Matrix<Num,>,) -> Self {
+    //Rotate pairs of columns until they are orthogonal, the same rotations build `V`.
+    let mut columns = matrix.columns();
+    let mut vectors = [Vector::X, Vector::Y, Vector::Z,];
+
+    for _ in 0..SWEEPS {
+      let mut changed = false;
+
+      for &(p, q,) in PAIRS.iter() {
+        let dot = columns[p].clone() * columns[q].clone();
+
+        if dot == Num::ZERO { continue }
+
+        let (cos, sin,) = jacobi(columns[p].clone().magnitude_squared(), columns[q].clone().magnitude_squared(), dot,);
+        let rotate = |[lhs, rhs,]: [Vector<Num,>; 2],| [
+          (lhs.clone() * cos.clone()) - (rhs.clone() * sin.clone()),
+          (lhs * sin.clone()) + (rhs * cos.clone()),
+        ];
+        let rotated = rotate([columns[p].clone(), columns[q].clone(),],);
+
+        changed |= rotated[0] != columns[p] || rotated[1] != columns[q];
+        [columns[p], columns[q],] = rotated;
+        [vectors[p], vectors[q],] = rotate([vectors[p].clone(), vectors[q].clone(),],);
+      }
+
+      if !changed { break }
+    }
+
+    let mut values = columns.clone().map(Vector::magnituid,);
+
+    //Sort the columns along with their singular values.
+    let mut pairs = [(columns[0].clone(), vectors[0].clone(),), (columns[1].clone(), vectors[1].clone(),), (columns[2].clone(), vectors[2].clone(),),];
+
+    sort(&mut values, &mut pairs, |lhs, rhs,| lhs > rhs,);
+
+    let [(a, va,), (b, vb,), (c, vc,),] = pairs;
+    let [sa, sb, sc,] = values.clone();
+    let u = complete([(a, sa,), (b, sb,), (c, sc,),],);
+
+    Self { u, values: Vector::from(values,), v: Matrix::from_columns([va, vb, vc,],), }
+  }
+}
+
+/// Builds the orthonormal columns of `U` from the rotated columns and their lengths.
+/// 
+/// Columns with a length of zero are replaced to complete the orthonormal basis.
+fn complete<Num: Sqrt + Clone,>(columns: [(Vector<Num,>, Num,); 3],) -> Matrix<Num,> {
+  let [(a, sa,), (b, sb,), (c, sc,),] = columns;
+
+  if sa == Num::ZERO { return Matrix::IDENTITY }
+
+  let a = a / sa;
+  let b = if sb != Num::ZERO { b / sb } else {
+    //Remove the component along `a` from the axis least aligned with it.
+    let axes = [Vector::X, Vector::Y, Vector::Z,];
+    let mut axis = Vector::X;
+
+    for candidate in axes.iter() {
+      if abs(a.clone() * candidate.clone(),) < abs(a.clone() * axis.clone(),) { axis = candidate.clone() }
+    }
+
+    (axis.clone() - (a.clone() * (a.clone() * axis))).unit().vector()
+  };
+  let c = if sc != Num::ZERO { c / sc } else { Vector::cross(a.clone(), b.clone(),) };
+
+  Matrix::from_columns([a, b, c,],)
+}
+
+/// Solves `U * x = rhs` for `x`, using the upper triangle of `upper`.
+/// 
+/// Returns `None` if `upper` has a zero on its diagonal.
+fn back_substitute<Num: Number + Clone,>(upper: &Matrix<Num,>, rhs: Vector<Num,>,) -> Option<Vector<Num,>> {
+  let mut solution = rhs;
+
+  for row in (0..3).rev() {
+    if upper[row][row] == Num::ZERO { return None }
+
+    let mut sum = solution[row].clone();
+
+    for column in row + 1..3 { sum = sum - (upper[row][column].clone() * solution[column].clone()) }
+    solution[row] = sum / upper[row][row].clone();
+  }
+
+  Some(solution)
+}
+
+/// Returns the cosine and sine of the Jacobi rotation which diagonalises the symmetric
+/// matrix `[[app, apq], [apq, aqq]]`.
+/// 
+/// `apq` may not be zero.
+fn jacobi<Num: Sqrt + Clone,>(app: Num, aqq: Num, apq: Num,) -> (Num, Num,) {
+  //`tan` is the smaller root of `tan^2 + 2 tan cot(2 angle) - 1 = 0`, written to avoid
+  //dividing by `apq`.
+  let twice = apq * Num::from_isize(2,);
+  let difference = aqq - app;
+  let hypot = ((difference.clone() * difference.clone()) + (twice.clone() * twice.clone())).sqrt();
+  let tan = if difference < Num::ZERO { twice / (difference - hypot) }
+    else { twice / (difference + hypot) };
+  let cos = Num::ONE / ((tan.clone() * tan.clone()) + Num::ONE).sqrt();
+
+  (cos.clone(), tan * cos,)
+}
+
+/// Returns the Matrix which rotates in the plane of the axes `p` and `q`.
+fn rotation<Num: Number + Clone,>(p: usize, q: usize, (cos, sin,): (Num, Num,),) -> Matrix<Num,> {
+  let mut rotation = Matrix::IDENTITY;
+
+  rotation[p][p] = cos.clone();
+  rotation[q][q] = cos;
+  rotation[p][q] = sin.clone();
+  rotation[q][p] = -sin;
+
+  rotation
+}
+
+/// Mirrors the upper triangle of `matrix` into its lower triangle.
+fn symmetric<Num: Clone,>(mut matrix: Matrix<Num,>,) -> Matrix<Num,> {
+  for &(p, q,) in PAIRS.iter() { matrix[q][p] = matrix[p][q].clone() }
+
+  matrix
+}
+
+/// Sorts `keys` and `values` together using `before` as the ordering.
+fn sort<Key, Value, F,>(keys: &mut [Key; 3], values: &mut [Value; 3], mut before: F,)
+  where F: FnMut(&Key, &Key,) -> bool, {
+  for &(lhs, rhs,) in PAIRS.iter() {
+    if before(&keys[rhs], &keys[lhs],) {
+      keys.swap(lhs, rhs,);
+      values.swap(lhs, rhs,);
+    }
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  /// A general Matrix which needs pivoting.
+  const MATRIX: Matrix<f64,> = Matrix::new([
+    Vector::new(0.0, 2.0, 1.0,),
+    Vector::new(3.0, -1.0, 2.0,),
+    Vector::new(1.0, 4.0, -2.0,),
+  ],);
+
+  #[test]
+  fn test_lu() {
+    let lu = Lu::new(MATRIX,);
+    let permuted = Matrix::new(lu.permutation().map(|row,| MATRIX[row],),);
+    assert_abs_diff_eq!(lu.lower() * lu.upper(), permuted, 1e-12, "Factors failed",);
+    assert_abs_diff_eq!(lu.determinant(), MATRIX.determinant(), 1e-12, "determinant failed",);
+
+    let rhs = Vector::new(1.0, -2.0, 0.5,);
+    let solution = lu.solve(rhs,).expect("solve failed");
+    assert_abs_diff_eq!(MATRIX * solution, rhs, 1e-12, "solve failed",);
+    assert_abs_diff_eq!(lu.inverse().expect("inverse failed") * MATRIX, Matrix::IDENTITY, 1e-12, "inverse failed",);
+
+    let singular = Matrix::new([Vector::new(1.0, 2.0, 3.0,), Vector::new(2.0, 4.0, 6.0,), Vector::new(0.0, 1.0, 1.0,),],);
+    assert_eq!(Lu::new(singular,).solve(rhs,), None, "Singular solve accepted",);
+    assert_eq!(Lu::new(singular,).determinant(), 0.0, "Singular determinant failed",);
+  }
+  #[test]
+  fn test_qr() {
+    let qr = Qr::new(MATRIX,);
+    assert_abs_diff_eq!(qr.q * qr.r, MATRIX, 1e-12, "Factors failed",);
+    assert_abs_diff_eq!(qr.q.transpose() * qr.q, Matrix::IDENTITY, 1e-12, "Q is not orthogonal",);
+    assert!(qr.r[1][0] == 0.0 && qr.r[2][0] == 0.0 && qr.r[2][1] == 0.0, "R is not upper triangular",);
+
+    let rhs = Vector::new(1.0, -2.0, 0.5,);
+    assert_abs_diff_eq!(MATRIX * qr.solve(rhs,).expect("solve failed"), rhs, 1e-12, "solve failed",);
+    assert_eq!(Qr::new(Matrix::<f64>::ZERO,).solve(rhs,), None, "Singular solve accepted",);
+  }
+  #[test]
+  fn test_symmetric_eigen() {
+    let matrix = Matrix::new([Vector::new(2.0, 1.0, 0.0,), Vector::new(1.0, 2.0, 0.0,), Vector::new(0.0, 0.0, 5.0,),],);
+    let eigen = SymmetricEigen::new(matrix,);
+    assert_abs_diff_eq!(Vector::from(eigen.values,), Vector::new(1.0, 3.0, 5.0,), 1e-12, "values failed",);
+
+    //An inertia tensor with no zero elements.
+    let matrix = Matrix::new([Vector::new(4.0, -1.0, 0.5,), Vector::new(-1.0, 3.0, -0.25,), Vector::new(0.5, -0.25, 6.0,),],);
+    let eigen = SymmetricEigen::new(matrix,);
+    assert!(eigen.values[0] <= eigen.values[1] && eigen.values[1] <= eigen.values[2], "values are not sorted",);
+    for (&value, &vector,) in eigen.values.iter().zip(eigen.vectors.iter(),) {
+      assert_abs_diff_eq!(matrix * vector, vector * value, 1e-12, "vectors failed",);
+    }
+    assert_abs_diff_eq!(Matrix::from_columns(eigen.vectors,).transpose() * Matrix::from_columns(eigen.vectors,), Matrix::IDENTITY, 1e-12, "vectors are not orthonormal",);
+
+    //Fit a plane to points by taking the direction of least variance.
+    let normal = Vector::<f64>::new(1.0, -2.0, 2.0,) / 3.0;
+    let points = [(1.0, 0.0,), (0.0, 1.0,), (-2.0, 0.5,), (3.0, -1.0,), (0.5, 2.0,),]
+      .map(|(x, y,),| Vector::new(x, y, (y * 2.0 - x) / 2.0 + 1.5,),);
+    let centre = points.iter().fold(Vector::ZERO, |sum, &point,| sum + point,) / 5.0;
+    let covariance = points.iter().fold(Matrix::ZERO, |sum, &point,| sum + Matrix::outer(point - centre, point - centre,),);
+    let fitted = SymmetricEigen::new(covariance,).vectors[0];
+    assert_abs_diff_eq!((fitted * normal).abs(), 1.0, 1e-12, "Plane fit failed",);
+  }
+  #[test]
+  fn test_svd() {
+    let svd = Svd::new(MATRIX,);
+    assert_abs_diff_eq!(svd.u * Matrix::diagonal(svd.values,) * svd.v.transpose(), MATRIX, 1e-12, "Factors failed",);
+    assert_abs_diff_eq!(svd.u.transpose() * svd.u, Matrix::IDENTITY, 1e-12, "U is not orthogonal",);
+    assert_abs_diff_eq!(svd.v.transpose() * svd.v, Matrix::IDENTITY, 1e-12, "V is not orthogonal",);
+    assert!(svd.values.x >= svd.values.y && svd.values.y >= svd.values.z && svd.values.z > 0.0, "values are not sorted",);
+    assert_abs_diff_eq!(svd.values.x * svd.values.y * svd.values.z, MATRIX.determinant().abs(), 1e-12, "values failed",);
+
+    //A rank one Matrix still has orthogonal factors.
+    let matrix = Matrix::outer(Vector::new(1.0, 2.0, 2.0,), Vector::new(0.0, 3.0, 4.0,),);
+    let svd = Svd::new(matrix,);
+    assert_abs_diff_eq!(svd.values, Vector::new(15.0, 0.0, 0.0,), 1e-12, "Rank one values failed",);
+    assert_abs_diff_eq!(svd.u * Matrix::diagonal(svd.values,) * svd.v.transpose(), matrix, 1e-12, "Rank one factors failed",);
+    assert_abs_diff_eq!(svd.u.transpose() * svd.u, Matrix::IDENTITY, 1e-12, "Rank one U is not orthogonal",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_decomposition_fixed() {
+    use fixed_point::{Fixed64, consts::U32,};
+
+    let matrix = Matrix::new(MATRIX.rows.map(|row,| row.map(Fixed64::<U32>::from,),),);
+    let rhs = Vector::new(1.into(), (-2).into(), 0.5.into(),);
+    let epsilon = Fixed64::from(1e-6,);
+    assert_abs_diff_eq!(matrix * Lu::new(matrix,).solve(rhs,).expect("Fixed64 solve failed"), rhs, epsilon, "Fixed64 LU failed",);
+    assert_abs_diff_eq!(matrix * Qr::new(matrix,).solve(rhs,).expect("Fixed64 solve failed"), rhs, epsilon, "Fixed64 QR failed",);
+
+    let symmetric = matrix + matrix.transpose();
+    let eigen = SymmetricEigen::new(symmetric,);
+    for (&value, &vector,) in eigen.values.iter().zip(eigen.vectors.iter(),) {
+      assert_abs_diff_eq!(symmetric * vector, vector * value, epsilon, "Fixed64 eigen failed",);
+    }
+  }
+}
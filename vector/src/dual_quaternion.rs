@@ -0,0 +1,319 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::ops;
+
+/// A quaternion `w + xi + yj + zk` stored as a scalar and a [Vector] part.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+struct Quaternion<Num,> {
+  /// The scalar part.
+  w: Num,
+  /// The vector part.
+  v: Vector<Num,>,
+}
+
+impl<Num,> Quaternion<Num,> {
+  #[inline]
+  const fn new(w: Num, v: Vector<Num,>,) -> Self { Self { w, v, } }
+}
+
+impl<Num: Number + Clone,> Quaternion<Num,> {
+  #[inline]
+  fn conjugate(self,) -> Self { Self::new(self.w, -self.v,) }
+  #[inline]
+  fn dot(lhs: Self, rhs: Self,) -> Num { (lhs.w * rhs.w) + (lhs.v * rhs.v) }
+  #[inline]
+  fn scale(self, scale: Num,) -> Self { Self::new(self.w * scale.clone(), self.v * scale,) }
+}
+
+impl<Num: Number + Clone,> ops::Neg for Quaternion<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn neg(self,) -> Self::Output { Self::new(-self.w, -self.v,) }
+}
+
+impl<Num: Number + Clone,> ops::Add for Quaternion<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn add(self, rhs: Self,) -> Self::Output { Self::new(self.w + rhs.w, self.v + rhs.v,) }
+}
+
+impl<Num: Number + Clone,> ops::Sub for Quaternion<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn sub(self, rhs: Self,) -> Self::Output { Self::new(self.w - rhs.w, self.v - rhs.v,) }
+}
+
+impl<Num: Number + Clone,> ops::Mul for Quaternion<Num,> {
+  type Output = Self;
+
+  fn mul(self, rhs: Self,) -> Self::Output {
+    /*
+    (w1 + v1)(w2 + v2) = (w1w2 - v1.v2) + (w1v2 + w2v1 + v1 x v2)
+    */
+    let (w1, a,) = (self.w, self.v,);
+    let (w2, b,) = (rhs.w, rhs.v,);
+    let w = (w1.clone() * w2.clone()) - (a.clone() * b.clone());
+    let v = Vector::new(
+      (w1.clone() * b.x.clone()) + (w2.clone() * a.x.clone())
+        + (a.y.clone() * b.z.clone()) - (a.z.clone() * b.y.clone()),
+      (w1.clone() * b.y.clone()) + (w2.clone() * a.y.clone())
+        + (a.z.clone() * b.x.clone()) - (a.x.clone() * b.z.clone()),
+      (w1 * b.z) + (w2 * a.z) + (a.x * b.y) - (a.y * b.x),
+    );
+
+    Self::new(w, v,)
+  }
+}
+
+/// A rigid body transformation combining a rotation and a translation.
+/// 
+/// Unlike interpolating a [Rotation] and a translation separately, blending
+/// DualQuaternions with [DualQuaternion::sclerp] moves along a single screw motion which
+/// avoids distorting skinned meshes.
+/// 
+/// Most operations assume the DualQuaternion is normalised, see
+/// [DualQuaternion::normalize].
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct DualQuaternion<Num,> {
+  /// The rotation part.
+  real: Quaternion<Num,>,
+  /// Half the translation multiplied by the rotation.
+  dual: Quaternion<Num,>,
+}
+
+impl<Num: Number,> DualQuaternion<Num,> {
+  /// The transformation which leaves every point unchanged.
+  pub const IDENTITY: Self = Self {
+    real: Quaternion::new(Num::ONE, Vector::ZERO,),
+    dual: Quaternion::new(Num::ZERO, Vector::ZERO,),
+  };
+}
+
+impl<Num: Number + Clone,> DualQuaternion<Num,> {
+  /// Builds the DualQuaternion of a pure translation.
+  /// 
+  /// # Params
+  /// 
+  /// translation --- The translation to apply.  
+  pub fn from_translation(translation: Vector<Num,>,) -> Self {
+    Self {
+      real: Quaternion::new(Num::ONE, Vector::ZERO,),
+      dual: Quaternion::new(Num::ZERO, translation / Num::from_isize(2,),),
+    }
+  }
+  /// Returns the conjugate of the rotation and translation parts.
+  /// 
+  /// For a normalised DualQuaternion this is the inverse transformation.
+  #[inline]
+  pub fn inverse(self,) -> Self {
+    Self { real: self.real.conjugate(), dual: self.dual.conjugate(), }
+  }
+  /// Returns the translation applied after the rotation.
+  pub fn translation(&self,) -> Vector<Num,> {
+    (self.dual.clone() * self.real.clone().conjugate()).v * Num::from_isize(2,)
+  }
+  /// Rotates `vector` without translating it.
+  /// 
+  /// # Params
+  /// 
+  /// vector --- The direction to rotate.  
+  pub fn transform_vector(&self, vector: Vector<Num,>,) -> Vector<Num,> {
+    let vector = Quaternion::new(Num::ZERO, vector,);
+
+    (self.real.clone() * vector * self.real.clone().conjugate()).v
+  }
+  /// Rotates and then translates `point`.
+  /// 
+  /// # Params
+  /// 
+  /// point --- The point to transform.  
+  pub fn transform_point(&self, point: Point<Num,>,) -> Point<Num,> {
+    Point::from_vector(self.transform_vector(point.to_vector(),),) + self.translation()
+  }
+}
+
+impl<Num,> DualQuaternion<Num,>
+  where Num: Sqrt + Trigonometry + Clone, {
+  /// Builds the DualQuaternion which rotates and then translates.
+  /// 
+  /// # Params
+  /// 
+  /// rotation --- The rotation to apply.  
+  /// translation --- The translation to apply after the rotation.  
+  pub fn new(rotation: &Rotation<Num,>, translation: Vector<Num,>,) -> Self {
+    let (sin, cos,) = (rotation.angle.clone() / Num::from_isize(2,)).sin_cos();
+    let real = Quaternion::new(cos, rotation.axis.clone().vector() * sin,);
+    let translation = Quaternion::new(Num::ZERO, translation,);
+    let dual = (translation * real.clone()).scale(Num::ONE / Num::from_isize(2,),);
+
+    Self { real, dual, }
+  }
+  /// Builds the DualQuaternion of a pure rotation.
+  /// 
+  /// # Params
+  /// 
+  /// rotation --- The rotation to apply.  
+  #[inline]
+  pub fn from_rotation(rotation: &Rotation<Num,>,) -> Self { Self::new(rotation, Vector::ZERO,) }
+  /// Returns the rotation applied before the translation.
+  pub fn rotation(&self,) -> Rotation<Num,> {
+    let sin = self.real.v.clone().magnituid();
+    let angle = sin.atan2(self.real.w.clone(),) * Num::from_isize(2,);
+
+    Rotation::new(self.real.v.clone().normalize_or(Unit::X,), Radians(angle,),)
+  }
+  /// Returns this DualQuaternion scaled to unit length.
+  /// 
+  /// The dual part is also corrected to be orthogonal to the real part which removes
+  /// drift accumulated over many compositions.
+  /// 
+  /// # Panics
+  /// 
+  /// If the rotation part is zero.
+  pub fn normalize(self,) -> Self {
+    let length = Quaternion::dot(self.real.clone(), self.real.clone(),).sqrt();
+
+    assert!(length != Num::ZERO, "Cannot normalise a zero length DualQuaternion",);
+
+    let real = self.real.scale(Num::ONE / length.clone(),);
+    let dual = self.dual.scale(Num::ONE / length,);
+    let drift = Quaternion::dot(real.clone(), dual.clone(),);
+    let dual = dual - real.clone().scale(drift,);
+
+    Self { real, dual, }
+  }
+  /// Interpolates along the screw motion between this DualQuaternion and `to`.
+  /// 
+  /// Both DualQuaternions are expected to be normalised.
+  /// 
+  /// # Params
+  /// 
+  /// to --- The transformation to interpolate towards.  
+  /// t --- The interpolation factor, `0` returns this transformation and `1` returns `to`.  
+  pub fn sclerp(self, to: Self, t: Num,) -> Self {
+    let mut diff = self.clone().inverse() * to;
+    //Take the shortest path between the two rotations.
+    if diff.real.w < Num::ZERO { diff = Self { real: -diff.real, dual: -diff.dual, } }
+
+    self * diff.pow(t,)
+  }
+  /// Raises this normalised DualQuaternion to the power `t` by scaling its screw
+  /// parameters.
+  fn pow(self, t: Num,) -> Self {
+    let two = Num::from_isize(2,);
+    let sin = self.real.v.clone().magnituid();
+
+    //A pure translation has no screw axis, scale the translation directly.
+    if sin == Num::ZERO {
+      return Self { real: self.real, dual: self.dual.scale(t,), }
+    }
+
+    let direction = self.real.v.clone() / sin.clone();
+    let angle = sin.clone().atan2(self.real.w.clone(),) * two.clone();
+    let pitch = -(self.dual.w.clone() * two.clone()) / sin.clone();
+    let moment = (self.dual.v.clone()
+      - (direction.clone() * (pitch.clone() * self.real.w.clone() / two.clone()))) / sin;
+
+    let half_angle = angle * t.clone() / two.clone();
+    let half_pitch = pitch * t / two;
+    let (sin, cos,) = half_angle.sin_cos();
+    let real = Quaternion::new(cos.clone(), direction.clone() * sin.clone(),);
+    let dual = Quaternion::new(
+      -(half_pitch.clone() * sin.clone()),
+      (moment * sin) + (direction * (half_pitch * cos)),
+    );
+
+    Self { real, dual, }
+  }
+}
+
+/// Composes two transformations, `rhs` is applied first.
+impl<Num: Number + Clone,> ops::Mul for DualQuaternion<Num,> {
+  type Output = Self;
+
+  fn mul(self, rhs: Self,) -> Self::Output {
+    Self {
+      real: self.real.clone() * rhs.real.clone(),
+      dual: (self.real * rhs.dual) + (self.dual * rhs.real),
+    }
+  }
+}
+
+impl<Num: Number + Clone,> ops::MulAssign for DualQuaternion<Num,> {
+  #[inline]
+  fn mul_assign(&mut self, rhs: Self,) { *self = self.clone() * rhs }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+  use std::f64::consts::{FRAC_PI_2, FRAC_PI_4,};
+
+  #[test]
+  fn test_dual_quaternion() {
+    let rotation = Rotation::new(Unit::Z, Radians(FRAC_PI_2,),);
+    let transform = DualQuaternion::new(&rotation, Vector::new(1.0, 2.0, 3.0,),);
+    let point = Point::new(1.0, 0.0, 0.0,);
+
+    assert_approx_eq!(transform.translation(), Vector::new(1.0, 2.0, 3.0,), "translation failed",);
+    assert_approx_eq!(transform.rotation(), rotation, "rotation failed",);
+    assert_abs_diff_eq!(transform.transform_point(point,), Point::new(1.0, 3.0, 3.0,), 1e-12, "transform_point failed",);
+    assert_abs_diff_eq!(transform.transform_vector(Vector::X,), Vector::Y, 1e-12, "transform_vector failed",);
+    assert_abs_diff_eq!(
+      transform.inverse().transform_point(transform.transform_point(point,),), point, 1e-12,
+      "inverse failed",
+    );
+    assert_eq!(DualQuaternion::IDENTITY.transform_point(point,), point, "IDENTITY failed",);
+
+    let translate = DualQuaternion::from_translation(Vector::new(0.0, 0.0, -3.0,),);
+    let composed = translate * transform;
+    assert_abs_diff_eq!(composed.transform_point(point,), Point::new(1.0, 3.0, 0.0,), 1e-12, "Composition failed",);
+
+    let scaled = DualQuaternion {
+      real: transform.real.scale(2.0,),
+      dual: transform.dual.scale(2.0,) + transform.real.scale(0.1,),
+    };
+    assert_abs_diff_eq!(
+      scaled.normalize().transform_point(point,), transform.transform_point(point,), 1e-12,
+      "normalize failed",
+    );
+  }
+  #[test]
+  fn test_sclerp() {
+    let from = DualQuaternion::IDENTITY;
+    let to = DualQuaternion::new(&Rotation::new(Unit::Z, Radians(FRAC_PI_2,),), Vector::new(2.0, 0.0, 4.0,),);
+
+    assert_abs_diff_eq!(from.sclerp(to, 0.0,).translation(), Vector::ZERO, 1e-12, "sclerp start failed",);
+    assert_abs_diff_eq!(from.sclerp(to, 1.0,).translation(), to.translation(), 1e-12, "sclerp end failed",);
+
+    let half = from.sclerp(to, 0.5,);
+    assert_approx_eq!(half.rotation().angle, Radians(FRAC_PI_4,), "sclerp rotation failed",);
+    assert_abs_diff_eq!(
+      (half * half).transform_point(Point::new(1.0, 1.0, 1.0,),),
+      to.transform_point(Point::new(1.0, 1.0, 1.0,),), 1e-12,
+      "sclerp screw failed",
+    );
+
+    let to = DualQuaternion::from_translation(Vector::new(2.0, 0.0, 4.0,),);
+    assert_approx_eq!(from.sclerp(to, 0.25,).translation(), Vector::new(0.5, 0.0, 1.0,), "sclerp translation failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_dual_quaternion_fixed() {
+    use fixed_point::{Fixed32, consts::U16,};
+
+    let rotation = Rotation::new(Unit::<Fixed32<U16>>::Z, Degrees(Fixed32::from(90,),),);
+    let transform = DualQuaternion::new(&rotation, Vector::new(1, 2, 3,).map(Fixed32::from,),);
+    let point = transform.transform_point(Point::from_vector(Vector::new(1, 0, 0,).map(Fixed32::from,),),);
+
+    assert_abs_diff_eq!(
+      point, Point::from_vector(Vector::new(1, 3, 3,).map(Fixed32::from,),), Fixed32::from(0.01,),
+      "Fixed32 transform_point failed",
+    );
+  }
+}
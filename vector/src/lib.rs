@@ -1,13 +1,47 @@
 //! An implementation of 3D vectors.
 //! 
+//! The crate is `no_std` when the default "std" feature is disabled, float functions are
+//! then provided by `libm` through the "libm" feature. [VectorBatch] and the binary
+//! encoding require the "alloc" feature.
+//! 
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-21
+//! Last Moddified --- 2026-10-19
 
 #![deny(missing_docs,)]
-#![feature(const_fn,)]
+#![cfg_attr(not(any(feature = "std", test,),), no_std,)]
+
+#[cfg(not(any(feature = "std", feature = "libm",),),)]
+compile_error!("either the \"std\" or the \"libm\" feature must be enabled",);
+
+#[cfg(feature = "alloc",)]
+extern crate alloc;
 
+#[macro_use]
+mod approx;
+mod math;
 mod number;
+mod angle;
 mod vector;
+mod point;
 mod rotation;
+mod bivector;
+mod rotor;
+mod matrix;
+mod decomposition;
+mod swizzle;
+mod coordinates;
+mod tagged;
+mod dual_quaternion;
+mod curve;
+#[cfg(feature = "alloc",)]
+mod batch;
+mod random;
+mod noise;
+mod roots;
+mod predicates;
+#[cfg(feature = "alloc",)]
+mod binary;
 
-pub use self::{number::*, vector::*, rotation::*,};
+pub use self::{approx::*, number::*, angle::*, vector::*, point::*, rotation::*, bivector::*, rotor::*, matrix::*, decomposition::*, coordinates::*, tagged::*, dual_quaternion::*, curve::*, random::*, noise::*, roots::*, predicates::*,};
+#[cfg(feature = "alloc",)]
+pub use self::{batch::*, binary::*,};
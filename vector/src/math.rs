@@ -0,0 +1,62 @@
+//! Float functions from `std`, or from `libm` when `std` is not available.
+//! 
+//! Functions are named after their `libm` equivalents.
+//! 
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+macro_rules! math {
+  ($($name:ident = $float:ident::$method:ident($($arg:ident,)*);)*) => {
+    $(
+      #[cfg(feature = "std",)]
+      #[inline]
+      pub(crate) fn $name(num: $float, $($arg: $float,)*) -> $float { $float::$method(num, $($arg,)*) }
+      #[cfg(not(feature = "std",),)]
+      #[inline]
+      pub(crate) fn $name(num: $float, $($arg: $float,)*) -> $float { libm::$name(num, $($arg,)*) }
+    )*
+  };
+}
+
+math! {
+  sqrtf = f32::sqrt();
+  floorf = f32::floor();
+  sinf = f32::sin();
+  cosf = f32::cos();
+  tanf = f32::tan();
+  asinf = f32::asin();
+  acosf = f32::acos();
+  atanf = f32::atan();
+  atan2f = f32::atan2(x,);
+  hypotf = f32::hypot(other,);
+  sqrt = f64::sqrt();
+  floor = f64::floor();
+  sin = f64::sin();
+  cos = f64::cos();
+  tan = f64::tan();
+  asin = f64::asin();
+  acos = f64::acos();
+  atan = f64::atan();
+  atan2 = f64::atan2(x,);
+  hypot = f64::hypot(other,);
+}
+
+/// The sine and cosine of `num`.
+#[cfg(feature = "std",)]
+#[inline]
+pub(crate) fn sincosf(num: f32,) -> (f32, f32,) { f32::sin_cos(num,) }
+
+/// The sine and cosine of `num`.
+#[cfg(not(feature = "std",),)]
+#[inline]
+pub(crate) fn sincosf(num: f32,) -> (f32, f32,) { libm::sincosf(num,) }
+
+/// The sine and cosine of `num`.
+#[cfg(feature = "std",)]
+#[inline]
+pub(crate) fn sincos(num: f64,) -> (f64, f64,) { f64::sin_cos(num,) }
+
+/// The sine and cosine of `num`.
+#[cfg(not(feature = "std",),)]
+#[inline]
+pub(crate) fn sincos(num: f64,) -> (f64, f64,) { libm::sincos(num,) }
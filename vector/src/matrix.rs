@@ -0,0 +1,185 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::ops;
+
+/// A 3x3 matrix stored as rows.
+/// 
+/// Indexing a Matrix returns a row so `matrix[row][column]` returns an element.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,),)]
+pub struct Matrix<Num,> {
+  /// The rows of the Matrix.
+  pub rows: [Vector<Num,>; 3],
+}
+
+impl<Num,> Matrix<Num,> {
+  /// Builds a new Matrix from its rows.
+  /// 
+  /// # Params
+  /// 
+  /// rows --- The rows of the Matrix.  
+  #[inline]
+  pub const fn new(rows: [Vector<Num,>; 3],) -> Self { Self { rows, } }
+  /// Returns this Matrix with its rows and columns swapped.
+  pub fn transpose(self,) -> Self {
+    let [x, y, z,] = self.rows;
+
+    Self::new([
+      Vector::new(x.x, y.x, z.x,),
+      Vector::new(x.y, y.y, z.y,),
+      Vector::new(x.z, y.z, z.z,),
+    ],)
+  }
+  /// Builds a new Matrix from its columns.
+  /// 
+  /// # Params
+  /// 
+  /// columns --- The columns of the Matrix.  
+  #[inline]
+  pub fn from_columns(columns: [Vector<Num,>; 3],) -> Self { Self::new(columns,).transpose() }
+  /// Returns the columns of this Matrix.
+  #[inline]
+  pub fn columns(self,) -> [Vector<Num,>; 3] { self.transpose().rows }
+}
+
+impl<Num: Number,> Matrix<Num,> {
+  /// The Matrix with all elements zero.
+  pub const ZERO: Self = Self::new([Vector::ZERO, Vector::ZERO, Vector::ZERO,],);
+  /// The Matrix which leaves every Vector unchanged.
+  pub const IDENTITY: Self = Self::new([Vector::X, Vector::Y, Vector::Z,],);
+}
+
+impl<Num: Number + Clone,> Matrix<Num,> {
+  /// Builds a new Matrix with `diagonal` along its diagonal and zero elsewhere.
+  /// 
+  /// # Params
+  /// 
+  /// diagonal --- The diagonal of the Matrix.  
+  pub fn diagonal(diagonal: Vector<Num,>,) -> Self {
+    Self::new([
+      Vector::new(diagonal.x, Num::ZERO, Num::ZERO,),
+      Vector::new(Num::ZERO, diagonal.y, Num::ZERO,),
+      Vector::new(Num::ZERO, Num::ZERO, diagonal.z,),
+    ],)
+  }
+  /// Returns the outer product of two Vectors, the Matrix `lhs * rhs^T`.
+  /// 
+  /// # Params
+  /// 
+  /// lhs --- The Vector giving the rows.  
+  /// rhs --- The Vector giving the columns.  
+  pub fn outer(lhs: Vector<Num,>, rhs: Vector<Num,>,) -> Self {
+    Self::new([rhs.clone() * lhs.x, rhs.clone() * lhs.y, rhs * lhs.z,],)
+  }
+  /// Returns the sum of the diagonal of this Matrix.
+  #[inline]
+  pub fn trace(self,) -> Num {
+    let [x, y, z,] = self.rows;
+
+    x.x + y.y + z.z
+  }
+  /// Returns the determinant of this Matrix.
+  pub fn determinant(self,) -> Num {
+    let [x, y, z,] = self.rows;
+
+    (x.x * ((y.y.clone() * z.z.clone()) - (y.z.clone() * z.y.clone())))
+    + (x.y * ((y.z * z.x.clone()) - (y.x.clone() * z.z)))
+    + (x.z * ((y.x * z.y) - (y.y * z.x)))
+  }
+}
+
+impl<Num,> ops::Index<usize> for Matrix<Num,> {
+  type Output = Vector<Num,>;
+
+  #[inline]
+  fn index(&self, index: usize,) -> &Self::Output { &self.rows[index] }
+}
+
+impl<Num,> ops::IndexMut<usize> for Matrix<Num,> {
+  #[inline]
+  fn index_mut(&mut self, index: usize,) -> &mut Self::Output { &mut self.rows[index] }
+}
+
+impl<Num: Number + Clone,> ops::Neg for Matrix<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn neg(self,) -> Self::Output { Self::new(self.rows.map(ops::Neg::neg,),) }
+}
+
+impl<Num: Number + Clone,> ops::Add for Matrix<Num,> {
+  type Output = Self;
+
+  fn add(self, rhs: Self,) -> Self::Output {
+    let [x, y, z,] = self.rows;
+    let [rx, ry, rz,] = rhs.rows;
+
+    Self::new([x + rx, y + ry, z + rz,],)
+  }
+}
+
+impl<Num: Number + Clone,> ops::Sub for Matrix<Num,> {
+  type Output = Self;
+
+  fn sub(self, rhs: Self,) -> Self::Output {
+    let [x, y, z,] = self.rows;
+    let [rx, ry, rz,] = rhs.rows;
+
+    Self::new([x - rx, y - ry, z - rz,],)
+  }
+}
+
+impl<Num: Number + Clone,> ops::Mul<Num> for Matrix<Num,> {
+  type Output = Self;
+
+  #[inline]
+  fn mul(self, rhs: Num,) -> Self::Output { Self::new(self.rows.map(|row,| row * rhs.clone(),),) }
+}
+
+impl<Num: Number + Clone,> ops::Mul<Vector<Num,>> for Matrix<Num,> {
+  type Output = Vector<Num,>;
+
+  fn mul(self, rhs: Vector<Num,>,) -> Self::Output {
+    let [x, y, z,] = self.rows;
+
+    Vector::new(x * rhs.clone(), y * rhs.clone(), z * rhs,)
+  }
+}
+
+impl<Num: Number + Clone,> ops::Mul for Matrix<Num,> {
+  type Output = Self;
+
+  fn mul(self, rhs: Self,) -> Self::Output {
+    let columns = rhs.columns();
+
+    Self::new(self.rows.map(|row,| Vector::from(columns.clone().map(|column,| row.clone() * column,),),),)
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_matrix() {
+    let matrix = Matrix::new([Vector::new(1, 2, 3,), Vector::new(4, 5, 6,), Vector::new(7, 8, 10,),],);
+    assert_eq!(matrix[1][2], 6, "Index failed",);
+    assert_eq!(matrix.transpose()[1][2], 8, "transpose failed",);
+    assert_eq!(Matrix::from_columns(matrix.rows,), matrix.transpose(), "from_columns failed",);
+    assert_eq!(matrix.columns()[0], Vector::new(1, 4, 7,), "columns failed",);
+    assert_eq!(matrix.trace(), 16, "trace failed",);
+    assert_eq!(matrix.determinant(), -3, "determinant failed",);
+    assert_eq!(Matrix::diagonal(Vector::new(1, 2, 3,),).determinant(), 6, "diagonal failed",);
+
+    assert_eq!(matrix * Vector::new(1, 0, -1,), Vector::new(-2, -2, -3,), "Vector product failed",);
+    assert_eq!(matrix * Matrix::IDENTITY, matrix, "Identity failed",);
+    assert_eq!((matrix * matrix)[2], Vector::new(109, 134, 169,), "Matrix product failed",);
+    assert_eq!((matrix * matrix.transpose()).determinant(), 9, "Product determinant failed",);
+    assert_eq!(matrix + matrix, matrix * 2, "Add failed",);
+    assert_eq!(matrix - matrix, Matrix::ZERO, "Sub failed",);
+    assert_eq!(-matrix + matrix, Matrix::ZERO, "Neg failed",);
+    assert_eq!(Matrix::outer(Vector::new(1, 2, 3,), Vector::new(1, 0, -1,),)[2], Vector::new(3, 0, -3,), "outer failed",);
+  }
+}
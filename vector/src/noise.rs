@@ -0,0 +1,417 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+
+/// A seeded source of coherent noise.
+/// 
+/// Perlin and value noise are computed using only the arithmetic of the [Number] type
+/// so fixed point noise is identical on every platform. Simplex noise also takes a
+/// [Sqrt] of the dimension count, which computes fixed point numbers through `f32` or
+/// `f64`.
+#[derive(PartialEq, Eq, Clone, Debug, Hash,)]
+pub struct Noise {
+  /// The shuffled lattice hash table.
+  permutation: [u8; 256],
+}
+
+impl Noise {
+  /// Creates a new [Noise] from a seed.
+  /// 
+  /// # Params
+  /// 
+  /// seed --- The seed of the noise.  
+  pub fn new(seed: u64,) -> Self {
+    let mut rng = Rng::new(seed,);
+    let mut permutation = [0; 256];
+
+    for (index, value,) in permutation.iter_mut().enumerate() { *value = index as u8 }
+    //Fisher-Yates shuffle.
+    for index in (1..permutation.len()).rev() {
+      let other = rng.next_u32() as usize % (index + 1);
+
+      permutation.swap(index, other,);
+    }
+
+    Self { permutation, }
+  }
+  /// Hashes a lattice cell.
+  fn hash<const N: usize,>(&self, cell: [isize; N],) -> usize {
+    cell.iter().fold(0, |hash, &coordinate,| {
+      self.permutation[(hash as isize).wrapping_add(coordinate,) as usize & 0xFF] as usize
+    },)
+  }
+  /// Interpolates the values at the corners of a lattice cell.
+  fn lattice<Num, Corner, const N: usize,>(&self, point: [Num; N], mut corner: Corner,) -> Num
+    where Num: Floor + Copy, Corner: FnMut(usize, [Num; N],) -> Num, {
+    let cell = point.map(Floor::floor,);
+    let mut offset = point;
+
+    for (offset, &cell,) in offset.iter_mut().zip(cell.iter(),) {
+      *offset = *offset - Num::from_isize(cell,);
+    }
+
+    //Enough corners for up to four dimensions.
+    let mut values = [Num::ZERO; 16];
+
+    for (index, value,) in values.iter_mut().enumerate().take(1 << N,) {
+      let (mut cell, mut delta,) = (cell, offset,);
+
+      for axis in (0..N).filter(|axis,| index >> axis & 1 == 1,) {
+        cell[axis] += 1;
+        delta[axis] = delta[axis] - Num::ONE;
+      }
+
+      *value = corner(self.hash(cell,), delta,);
+    }
+
+    //Collapse one axis at a time.
+    let mut len = 1 << N;
+
+    for &offset in offset.iter() {
+      let fade = fade(offset,);
+
+      len /= 2;
+      for index in 0..len {
+        let (low, high,) = (values[2 * index], values[2 * index + 1],);
+
+        values[index] = low + ((high - low) * fade);
+      }
+    }
+
+    values[0]
+  }
+  /// Sums the contributions of the corners of the simplex containing `point`.
+  fn simplex<Num, const N: usize,>(&self, point: [Num; N], radius: Num, scale: Num,) -> Num
+    where Num: Floor + Sqrt + Copy, {
+    let dimensions = Num::from_isize(N as isize,);
+    let root = (dimensions + Num::ONE).sqrt();
+    let skew = (root - Num::ONE) / dimensions;
+    let unskew = (Num::ONE - (Num::ONE / root)) / dimensions;
+    let skewed = point.iter().fold(Num::ZERO, |sum, &coordinate,| sum + coordinate,) * skew;
+    let cell = point.map(|coordinate,| (coordinate + skewed).floor(),);
+    let unskewed = Num::from_isize(cell.iter().sum(),) * unskew;
+    let mut origin = point;
+
+    for (origin, &cell,) in origin.iter_mut().zip(cell.iter(),) {
+      *origin = *origin - (Num::from_isize(cell,) - unskewed);
+    }
+
+    //The rank of each axis decides the order the simplex corners are stepped through.
+    let mut rank = [0; N];
+
+    for (axis, rank,) in rank.iter_mut().enumerate() {
+      *rank = (0..N).filter(|&other,| {
+        origin[other] < origin[axis] || (origin[other] == origin[axis] && other < axis)
+      },).count();
+    }
+
+    let mut sum = Num::ZERO;
+
+    for corner in 0..=N {
+      let (mut cell, mut delta,) = (cell, origin,);
+      let mut distance = Num::ZERO;
+
+      for axis in 0..N {
+        if rank[axis] + corner >= N {
+          cell[axis] += 1;
+          delta[axis] = delta[axis] - Num::ONE;
+        }
+
+        delta[axis] = delta[axis] + (Num::from_isize(corner as isize,) * unskew);
+        distance = distance + (delta[axis] * delta[axis]);
+      }
+
+      let falloff = radius - distance;
+
+      if falloff > Num::ZERO {
+        let falloff = falloff * falloff;
+
+        sum = sum + (falloff * falloff * gradient(self.hash(cell,), delta,));
+      }
+    }
+
+    sum * scale
+  }
+}
+
+impl Noise {
+  /// Returns 2D Perlin noise in approximately `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// x --- The x coordinate to sample.  
+  /// y --- The y coordinate to sample.  
+  #[inline]
+  pub fn perlin2<Num: Floor + Copy,>(&self, x: Num, y: Num,) -> Num {
+    self.lattice([x, y,], gradient,)
+  }
+  /// Returns 3D Perlin noise in approximately `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// point --- The position to sample.  
+  #[inline]
+  pub fn perlin3<Num: Floor + Copy,>(&self, point: Vector<Num,>,) -> Num {
+    self.lattice([point.x, point.y, point.z,], gradient,)
+  }
+  /// Returns 4D Perlin noise in approximately `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// point --- The position to sample.  
+  /// w --- The fourth coordinate to sample, often time.  
+  #[inline]
+  pub fn perlin4<Num: Floor + Copy,>(&self, point: Vector<Num,>, w: Num,) -> Num {
+    self.lattice([point.x, point.y, point.z, w,], gradient,)
+  }
+  /// Returns 2D simplex noise in approximately `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// x --- The x coordinate to sample.  
+  /// y --- The y coordinate to sample.  
+  pub fn simplex2<Num: Floor + Sqrt + Copy,>(&self, x: Num, y: Num,) -> Num {
+    self.simplex([x, y,], Num::ONE / Num::from_isize(2,), Num::from_isize(70,),)
+  }
+  /// Returns 3D simplex noise in approximately `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// point --- The position to sample.  
+  pub fn simplex3<Num: Floor + Sqrt + Copy,>(&self, point: Vector<Num,>,) -> Num {
+    let radius = Num::from_isize(3,) / Num::from_isize(5,);
+
+    self.simplex([point.x, point.y, point.z,], radius, Num::from_isize(32,),)
+  }
+  /// Returns 4D simplex noise in approximately `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// point --- The position to sample.  
+  /// w --- The fourth coordinate to sample, often time.  
+  pub fn simplex4<Num: Floor + Sqrt + Copy,>(&self, point: Vector<Num,>, w: Num,) -> Num {
+    let radius = Num::from_isize(3,) / Num::from_isize(5,);
+
+    self.simplex([point.x, point.y, point.z, w,], radius, Num::from_isize(27,),)
+  }
+  /// Returns 2D value noise in `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// x --- The x coordinate to sample.  
+  /// y --- The y coordinate to sample.  
+  #[inline]
+  pub fn value2<Num: Floor + Copy,>(&self, x: Num, y: Num,) -> Num {
+    self.lattice([x, y,], |hash, _,| value(hash,),)
+  }
+  /// Returns 3D value noise in `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// point --- The position to sample.  
+  #[inline]
+  pub fn value3<Num: Floor + Copy,>(&self, point: Vector<Num,>,) -> Num {
+    self.lattice([point.x, point.y, point.z,], |hash, _,| value(hash,),)
+  }
+  /// Returns 4D value noise in `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// point --- The position to sample.  
+  /// w --- The fourth coordinate to sample, often time.  
+  #[inline]
+  pub fn value4<Num: Floor + Copy,>(&self, point: Vector<Num,>, w: Num,) -> Num {
+    self.lattice([point.x, point.y, point.z, w,], |hash, _,| value(hash,),)
+  }
+}
+
+/// The quintic fade curve `6t^5 - 15t^4 + 10t^3`.
+fn fade<Num: Number + Copy,>(t: Num,) -> Num {
+  t * t * t * ((t * ((t * Num::from_isize(6,)) - Num::from_isize(15,))) + Num::from_isize(10,))
+}
+
+/// Maps a hash to a value in `[-1, 1]`.
+fn value<Num: Number,>(hash: usize,) -> Num {
+  Num::from_isize((hash as isize * 2) - 0xFF,) / Num::from_isize(0xFF,)
+}
+
+/// The dot product of the gradient selected by `hash` and `delta`.
+/// 
+/// 2D noise uses the eight compass directions, higher dimensions use the edges of the
+/// unit hypercube.
+fn gradient<Num: Number + Copy, const N: usize,>(hash: usize, delta: [Num; N],) -> Num {
+  if N == 2 {
+    let (x, y,) = (delta[0], delta[1],);
+
+    return match hash & 7 {
+      0 => x + y,
+      1 => x - y,
+      2 => y - x,
+      3 => -x - y,
+      4 => x,
+      5 => -x,
+      6 => y,
+      _ => -y,
+    }
+  }
+
+  let (zero, mut signs,) = (hash % N, hash / N,);
+  let mut sum = Num::ZERO;
+
+  for (_, &delta,) in delta.iter().enumerate().filter(|&(axis, _,),| axis != zero,) {
+    sum = if signs & 1 == 0 { sum + delta } else { sum - delta };
+    signs >>= 1;
+  }
+
+  sum
+}
+
+/// Combines octaves of noise into fractal noise.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub struct Fractal<Num,> {
+  /// The number of octaves to sum.
+  pub octaves: usize,
+  /// The frequency multiplier between octaves.
+  pub lacunarity: Num,
+  /// The amplitude multiplier between octaves.
+  pub gain: Num,
+}
+
+impl<Num,> Fractal<Num,> {
+  /// Creates a new [Fractal] value.
+  /// 
+  /// # Params
+  /// 
+  /// octaves --- The number of octaves to sum.  
+  /// lacunarity --- The frequency multiplier between octaves.  
+  /// gain --- The amplitude multiplier between octaves.  
+  #[inline]
+  pub const fn new(octaves: usize, lacunarity: Num, gain: Num,) -> Self {
+    Self { octaves, lacunarity, gain, }
+  }
+}
+
+impl<Num: Number + Copy,> Fractal<Num,> {
+  /// Sums the octaves of `sample` normalised by the total amplitude.
+  fn sum<Sample,>(&self, mut sample: Sample,) -> Num
+    where Sample: FnMut(Num,) -> Num, {
+    let (mut frequency, mut amplitude,) = (Num::ONE, Num::ONE,);
+    let (mut sum, mut total,) = (Num::ZERO, Num::ZERO,);
+
+    for _ in 0..self.octaves {
+      sum = sum + (sample(frequency,) * amplitude);
+      total = total + amplitude;
+      frequency = frequency * self.lacunarity;
+      amplitude = amplitude * self.gain;
+    }
+
+    if total == Num::ZERO { total } else { sum / total }
+  }
+  /// Fractal Brownian motion, the range matches the range of `sample`.
+  /// 
+  /// # Params
+  /// 
+  /// sample --- Samples the noise at the frequency passed to it.  
+  #[inline]
+  pub fn fbm<Sample,>(&self, sample: Sample,) -> Num
+    where Sample: FnMut(Num,) -> Num, { self.sum(sample,) }
+  /// Sums the absolute value of each octave, in `[0, 1]` for noise in `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// sample --- Samples the noise at the frequency passed to it.  
+  pub fn turbulence<Sample,>(&self, mut sample: Sample,) -> Num
+    where Sample: FnMut(Num,) -> Num, {
+    self.sum(|frequency,| abs(sample(frequency,),),)
+  }
+  /// Sums octaves folded into sharp ridges, in `[0, 1]` for noise in `[-1, 1]`.
+  /// 
+  /// # Params
+  /// 
+  /// sample --- Samples the noise at the frequency passed to it.  
+  pub fn ridged<Sample,>(&self, mut sample: Sample,) -> Num
+    where Sample: FnMut(Num,) -> Num, {
+    self.sum(|frequency,| {
+      let ridge = Num::ONE - abs(sample(frequency,),);
+
+      ridge * ridge
+    },)
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_noise() {
+    let noise = Noise::new(7,);
+
+    assert_eq!(noise, Noise::new(7,), "Seeding failed 1",);
+    assert_ne!(noise, Noise::new(8,), "Seeding failed 2",);
+    assert_eq!(noise.perlin3(Vector::new(1.0, -2.0, 3.0,),), 0.0, "Perlin lattice failed",);
+    assert_eq!(noise.perlin2(4.0, 5.0,), 0.0, "Perlin2 lattice failed",);
+
+    let mut peaks = [0.0f64; 9];
+
+    for index in 0..2048 {
+      let t = index as f64 * 0.137;
+      let point = Vector::new(t.sin() * 9.0, t * 0.31, t.cos() * -5.0,);
+      let samples = [
+        noise.perlin2(point.x, point.y,),
+        noise.perlin3(point,),
+        noise.perlin4(point, t,),
+        noise.simplex2(point.x, point.y,),
+        noise.simplex3(point,),
+        noise.simplex4(point, t,),
+        noise.value2(point.x, point.y,),
+        noise.value3(point,),
+        noise.value4(point, t,),
+      ];
+
+      for (peak, sample,) in peaks.iter_mut().zip(samples.iter(),) {
+        assert!(sample.abs() <= 1.0, "Noise range failed",);
+        *peak = peak.max(sample.abs(),);
+      }
+
+      //Noise is continuous.
+      let step = point + Vector::new(1e-4, 1e-4, 1e-4,);
+      assert!((noise.perlin3(step,) - samples[1]).abs() < 1e-2, "Perlin continuity failed",);
+      assert!((noise.simplex3(step,) - samples[4]).abs() < 1e-2, "Simplex continuity failed",);
+    }
+
+    assert!(peaks.iter().all(|&peak,| peak > 0.4,), "Noise amplitude failed",);
+  }
+  #[test]
+  fn test_fractal() {
+    let noise = Noise::new(3,);
+    let point = Vector::new(0.3, 1.7, -2.2,);
+    let sample = |frequency: f64,| noise.simplex3(point * frequency,);
+
+    assert_eq!(Fractal::new(1, 2.0, 0.5,).fbm(sample,), sample(1.0,), "fbm failed 1",);
+    assert_eq!(Fractal::new(0, 2.0, 0.5,).fbm(sample,), 0.0, "fbm failed 2",);
+
+    let fractal = Fractal::new(5, 2.0, 0.5,);
+    assert!(fractal.fbm(sample,).abs() <= 1.0, "fbm failed 3",);
+    assert!((0.0..=1.0).contains(&fractal.turbulence(sample,),), "turbulence failed",);
+    assert!((0.0..=1.0).contains(&fractal.ridged(sample,),), "ridged failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_noise_fixed() {
+    use fixed_point::{Fixed32, consts::U16,};
+
+    let noise = Noise::new(11,);
+
+    for index in 0..256 {
+      let t = index as f32 * 0.173;
+      let point = Vector::new(t.sin() * 9.0, t * 0.31, t.cos() * -5.0,);
+      let fixed = Vector::<Fixed32<U16>>::new(point.x.into(), point.y.into(), point.z.into(),);
+
+      assert!((noise.perlin3(fixed,).to_f32() - noise.perlin3(point,)).abs() < 1e-2, "Fixed32 perlin failed",);
+      assert!((noise.simplex3(fixed,).to_f32() - noise.simplex3(point,)).abs() < 1e-2, "Fixed32 simplex failed",);
+      assert!((noise.value3(fixed,).to_f32() - noise.value3(point,)).abs() < 1e-2, "Fixed32 value failed",);
+    }
+  }
+}
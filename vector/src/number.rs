@@ -1,57 +1,106 @@
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-21
+//! Last Moddified --- 2026-10-19
 
 #[cfg(feature = "fixed-point",)]
 use fixed_point::Unsigned;
-use std::ops;
+use crate::math;
+use core::ops;
 
 /// Defines a type for numbers.
 pub trait Number: ops::Add<Output = Self>
   + ops::Sub<Output = Self>
   + ops::Mul<Output = Self>
   + ops::Div<Output = Self>
+  + ops::Rem<Output = Self>
   + ops::Neg<Output = Self>
+  + PartialOrd
   + Sized {
+  /// The additive identity.
+  const ZERO: Self;
+  /// The multiplicative identity.
+  const ONE: Self;
+  /// The negation of [Number::ONE].
+  const NEG_ONE: Self;
+  /// The rounding error of values close to [Number::ONE], the difference between `ONE`
+  /// and the next larger value.
+  /// 
+  /// Defaults to zero for types with exact arithmetic such as the integers.
+  const EPSILON: Self = Self::ZERO;
+
   /// Creates a new number from a `isize`.
   fn from_isize(from: isize,) -> Self;
 }
 
 impl Number for isize {
+  const ZERO: Self = 0;
+  const ONE: Self = 1;
+  const NEG_ONE: Self = -1;
+
   #[inline]
   fn from_isize(from: isize,) -> Self { from }
 }
 
 impl Number for i8 {
+  const ZERO: Self = 0;
+  const ONE: Self = 1;
+  const NEG_ONE: Self = -1;
+
   #[inline]
   fn from_isize(from: isize,) -> Self { from as i8 }
 }
 
 impl Number for i16 {
+  const ZERO: Self = 0;
+  const ONE: Self = 1;
+  const NEG_ONE: Self = -1;
+
   #[inline]
   fn from_isize(from: isize,) -> Self { from as i16 }
 }
 
 impl Number for i32 {
+  const ZERO: Self = 0;
+  const ONE: Self = 1;
+  const NEG_ONE: Self = -1;
+
   #[inline]
   fn from_isize(from: isize,) -> Self { from as i32 }
 }
 
 impl Number for i64 {
+  const ZERO: Self = 0;
+  const ONE: Self = 1;
+  const NEG_ONE: Self = -1;
+
   #[inline]
   fn from_isize(from: isize,) -> Self { from as i64 }
 }
 
 impl Number for i128 {
+  const ZERO: Self = 0;
+  const ONE: Self = 1;
+  const NEG_ONE: Self = -1;
+
   #[inline]
   fn from_isize(from: isize,) -> Self { from as i128 }
 }
 
 impl Number for f32 {
+  const ZERO: Self = 0.0;
+  const ONE: Self = 1.0;
+  const NEG_ONE: Self = -1.0;
+  const EPSILON: Self = f32::EPSILON;
+
   #[inline]
   fn from_isize(from: isize,) -> Self { from as f32 }
 }
 
 impl Number for f64 {
+  const ZERO: Self = 0.0;
+  const ONE: Self = 1.0;
+  const NEG_ONE: Self = -1.0;
+  const EPSILON: Self = f64::EPSILON;
+
   #[inline]
   fn from_isize(from: isize,) -> Self { from as f64 }
 }
@@ -59,6 +108,11 @@ impl Number for f64 {
 /// Included with feature "fixed-point".
 #[cfg(feature = "fixed-point",)]
 impl<Shift: Unsigned,> Number for fixed_point::Fixed32<Shift,> {
+  const ZERO: Self = Self::from_bits(0,);
+  const ONE: Self = Self::from_bits(1 << Shift::U32,);
+  const NEG_ONE: Self = Self::from_bits(-1 << Shift::U32,);
+  const EPSILON: Self = Self::from_bits(1,);
+
   #[inline]
   fn from_isize(from: isize,) -> Self { (from as i32).into() }
 }
@@ -66,10 +120,21 @@ impl<Shift: Unsigned,> Number for fixed_point::Fixed32<Shift,> {
 /// Included with feature "fixed-point".
 #[cfg(feature = "fixed-point",)]
 impl<Shift: Unsigned,> Number for fixed_point::Fixed64<Shift,> {
+  const ZERO: Self = Self::from_bits(0,);
+  const ONE: Self = Self::from_bits(1 << Shift::U32,);
+  const NEG_ONE: Self = Self::from_bits(-1 << Shift::U32,);
+  const EPSILON: Self = Self::from_bits(1,);
+
   #[inline]
   fn from_isize(from: isize,) -> Self { (from as i64).into() }
 }
 
+/// Returns the absolute value of `num`.
+#[inline]
+pub(crate) fn abs<Num: Number,>(num: Num,) -> Num {
+  if num < Num::ZERO { -num } else { num }
+}
+
 /// Defines a square root operation for a number type.
 pub trait Sqrt: Number {
   /// Returns the square root of this number.
@@ -78,24 +143,84 @@ pub trait Sqrt: Number {
 
 impl Sqrt for f32 {
   #[inline]
-  fn sqrt(self,) -> Self { f32::sqrt(self,) }
+  fn sqrt(self,) -> Self { math::sqrtf(self,) }
 }
 
 impl Sqrt for f64 {
   #[inline]
-  fn sqrt(self,) -> Self { f64::sqrt(self,) }
+  fn sqrt(self,) -> Self { math::sqrt(self,) }
 }
 
 /// Included with feature "fixed-point".
 #[cfg(feature = "fixed-point",)]
 impl<Shift: Unsigned,> Sqrt for fixed_point::Fixed32<Shift,> {
-  fn sqrt(self,) -> Self { self.to_f32().sqrt().into() }
+  fn sqrt(self,) -> Self { math::sqrtf(self.to_f32(),).into() }
 }
 
 /// Included with feature "fixed-point".
 #[cfg(feature = "fixed-point",)]
 impl<Shift: Unsigned,> Sqrt for fixed_point::Fixed64<Shift,> {
-  fn sqrt(self,) -> Self { self.to_f64().sqrt().into() }
+  fn sqrt(self,) -> Self { math::sqrt(self.to_f64(),).into() }
+}
+
+/// Defines a rounding down operation for a number type.
+pub trait Floor: Number {
+  /// Returns the largest integer less than or equal to this number.
+  fn floor(self,) -> isize;
+}
+
+impl Floor for isize {
+  #[inline]
+  fn floor(self,) -> isize { self }
+}
+
+impl Floor for i8 {
+  #[inline]
+  fn floor(self,) -> isize { self as isize }
+}
+
+impl Floor for i16 {
+  #[inline]
+  fn floor(self,) -> isize { self as isize }
+}
+
+impl Floor for i32 {
+  #[inline]
+  fn floor(self,) -> isize { self as isize }
+}
+
+impl Floor for i64 {
+  #[inline]
+  fn floor(self,) -> isize { self as isize }
+}
+
+impl Floor for i128 {
+  #[inline]
+  fn floor(self,) -> isize { self as isize }
+}
+
+impl Floor for f32 {
+  #[inline]
+  fn floor(self,) -> isize { math::floorf(self,) as isize }
+}
+
+impl Floor for f64 {
+  #[inline]
+  fn floor(self,) -> isize { math::floor(self,) as isize }
+}
+
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> Floor for fixed_point::Fixed32<Shift,> {
+  #[inline]
+  fn floor(self,) -> isize { (self.to_bits() >> Shift::U32) as isize }
+}
+
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> Floor for fixed_point::Fixed64<Shift,> {
+  #[inline]
+  fn floor(self,) -> isize { (self.to_bits() >> Shift::U32) as isize }
 }
 
 /// Defines trigonometry operations for a number type.
@@ -112,54 +237,187 @@ pub trait Trigonometry: Number {
   fn acos(self,) -> Self;
   /// The inverse tangent of this number.
   fn atan(self,) -> Self;
+  /// The four quadrant inverse tangent of this number, `self` being the y coordinate.
+  /// 
+  /// # Params
+  /// 
+  /// x --- The x coordinate.  
+  fn atan2(self, x: Self,) -> Self;
+  /// The length of the hypotenuse of a right triangle with sides `self` and `other`.
+  /// 
+  /// # Params
+  /// 
+  /// other --- The length of the other side.  
+  fn hypot(self, other: Self,) -> Self;
+
+  /// The ratio of a circles circumference to its diameter.
+  const PI: Self;
+  /// The ratio of a circles circumference to its radius.
+  const TAU: Self;
+
+  /// The sine and cosine of this number.
+  #[inline]
+  fn sin_cos(self,) -> (Self, Self,)
+    where Self: Clone, { (self.clone().sin(), self.cos(),) }
+  /// Converts this number from degrees to radians.
+  #[inline]
+  fn to_radians(self,) -> Self { self * Self::PI / Self::from_isize(180,) }
+  /// Converts this number from radians to degrees.
+  #[inline]
+  fn to_degrees(self,) -> Self { self * Self::from_isize(180,) / Self::PI }
 }
 
 impl Trigonometry for f32 {
   #[inline]
-  fn sin(self,) -> Self { f32::sin(self,) }
+  fn sin(self,) -> Self { math::sinf(self,) }
+  #[inline]
+  fn cos(self,) -> Self { math::cosf(self,) }
   #[inline]
-  fn cos(self,) -> Self { f32::cos(self,) }
+  fn tan(self,) -> Self { math::tanf(self,) }
   #[inline]
-  fn tan(self,) -> Self { f32::tan(self,) }
+  fn asin(self,) -> Self { math::asinf(self,) }
+  #[inline]
+  fn acos(self,) -> Self { math::acosf(self,) }
+  #[inline]
+  fn atan(self,) -> Self { math::atanf(self,) }
+  #[inline]
+  fn atan2(self, x: Self,) -> Self { math::atan2f(self, x,) }
+  #[inline]
+  fn hypot(self, other: Self,) -> Self { math::hypotf(self, other,) }
+
+  const PI: Self = core::f32::consts::PI;
+  const TAU: Self = core::f32::consts::PI * 2.0;
+
   #[inline]
-  fn asin(self,) -> Self { f32::asin(self,) }
+  fn sin_cos(self,) -> (Self, Self,) { math::sincosf(self,) }
   #[inline]
-  fn acos(self,) -> Self { f32::acos(self,) }
+  fn to_radians(self,) -> Self { f32::to_radians(self,) }
   #[inline]
-  fn atan(self,) -> Self { f32::atan(self,) }
+  fn to_degrees(self,) -> Self { f32::to_degrees(self,) }
 }
 
 impl Trigonometry for f64 {
   #[inline]
-  fn sin(self,) -> Self { f64::sin(self,) }
+  fn sin(self,) -> Self { math::sin(self,) }
+  #[inline]
+  fn cos(self,) -> Self { math::cos(self,) }
+  #[inline]
+  fn tan(self,) -> Self { math::tan(self,) }
+  #[inline]
+  fn asin(self,) -> Self { math::asin(self,) }
+  #[inline]
+  fn acos(self,) -> Self { math::acos(self,) }
   #[inline]
-  fn cos(self,) -> Self { f64::cos(self,) }
+  fn atan(self,) -> Self { math::atan(self,) }
   #[inline]
-  fn tan(self,) -> Self { f64::tan(self,) }
+  fn atan2(self, x: Self,) -> Self { math::atan2(self, x,) }
   #[inline]
-  fn asin(self,) -> Self { f64::asin(self,) }
+  fn hypot(self, other: Self,) -> Self { math::hypot(self, other,) }
+
+  const PI: Self = core::f64::consts::PI;
+  const TAU: Self = core::f64::consts::PI * 2.0;
+
+  #[inline]
+  fn sin_cos(self,) -> (Self, Self,) { math::sincos(self,) }
   #[inline]
-  fn acos(self,) -> Self { f64::acos(self,) }
+  fn to_radians(self,) -> Self { f64::to_radians(self,) }
   #[inline]
-  fn atan(self,) -> Self { f64::atan(self,) }
+  fn to_degrees(self,) -> Self { f64::to_degrees(self,) }
 }
 
 #[cfg(feature = "fixed-point",)]
 impl<Shift: Unsigned,> Trigonometry for fixed_point::Fixed32<Shift,> {
-  fn sin(self,) -> Self { f32::sin(self.to_f32(),).into() }
-  fn cos(self,) -> Self { f32::cos(self.to_f32(),).into() }
-  fn tan(self,) -> Self { f32::tan(self.to_f32(),).into() }
-  fn asin(self,) -> Self { f32::asin(self.to_f32(),).into() }
-  fn acos(self,) -> Self { f32::acos(self.to_f32(),).into() }
-  fn atan(self,) -> Self { f32::atan(self.to_f32(),).into() }
+  fn sin(self,) -> Self { math::sinf(self.to_f32(),).into() }
+  fn cos(self,) -> Self { math::cosf(self.to_f32(),).into() }
+  fn tan(self,) -> Self { math::tanf(self.to_f32(),).into() }
+  fn asin(self,) -> Self { math::asinf(self.to_f32(),).into() }
+  fn acos(self,) -> Self { math::acosf(self.to_f32(),).into() }
+  fn atan(self,) -> Self { math::atanf(self.to_f32(),).into() }
+  fn atan2(self, x: Self,) -> Self { math::atan2f(self.to_f32(), x.to_f32(),).into() }
+  fn hypot(self, other: Self,) -> Self { math::hypotf(self.to_f32(), other.to_f32(),).into() }
+
+  const PI: Self = Self::from_bits(
+    ((core::f64::consts::PI * (1u64 << Shift::U32) as f64) + 0.5) as i32,
+  );
+  const TAU: Self = Self::from_bits(
+    ((core::f64::consts::PI * (2u64 << Shift::U32) as f64) + 0.5) as i32,
+  );
+
+  fn sin_cos(self,) -> (Self, Self,) {
+    let (sin, cos,) = math::sincosf(self.to_f32(),);
+
+    (sin.into(), cos.into(),)
+  }
 }
 
 #[cfg(feature = "fixed-point",)]
 impl<Shift: Unsigned,> Trigonometry for fixed_point::Fixed64<Shift,> {
-  fn sin(self,) -> Self { f64::sin(self.to_f64(),).into() }
-  fn cos(self,) -> Self { f64::cos(self.to_f64(),).into() }
-  fn tan(self,) -> Self { f64::tan(self.to_f64(),).into() }
-  fn asin(self,) -> Self { f64::asin(self.to_f64(),).into() }
-  fn acos(self,) -> Self { f64::acos(self.to_f64(),).into() }
-  fn atan(self,) -> Self { f64::atan(self.to_f64(),).into() }
+  fn sin(self,) -> Self { math::sin(self.to_f64(),).into() }
+  fn cos(self,) -> Self { math::cos(self.to_f64(),).into() }
+  fn tan(self,) -> Self { math::tan(self.to_f64(),).into() }
+  fn asin(self,) -> Self { math::asin(self.to_f64(),).into() }
+  fn acos(self,) -> Self { math::acos(self.to_f64(),).into() }
+  fn atan(self,) -> Self { math::atan(self.to_f64(),).into() }
+  fn atan2(self, x: Self,) -> Self { math::atan2(self.to_f64(), x.to_f64(),).into() }
+  fn hypot(self, other: Self,) -> Self { math::hypot(self.to_f64(), other.to_f64(),).into() }
+
+  const PI: Self = Self::from_bits(
+    ((core::f64::consts::PI * (1u64 << Shift::U32) as f64) + 0.5) as i64,
+  );
+  const TAU: Self = Self::from_bits(
+    ((core::f64::consts::PI * (2u64 << Shift::U32) as f64) + 0.5) as i64,
+  );
+
+  fn sin_cos(self,) -> (Self, Self,) {
+    let (sin, cos,) = math::sincos(self.to_f64(),);
+
+    (sin.into(), cos.into(),)
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_floor() {
+    assert_eq!(Floor::floor(2.5f32,), 2, "Floor failed 1",);
+    assert_eq!(Floor::floor(-2.5f64,), -3, "Floor failed 2",);
+    assert_eq!(Floor::floor(-7i32,), -7, "Floor failed 3",);
+  }
+  #[test]
+  fn test_trigonometry() {
+    assert_approx_eq!(Trigonometry::atan2(1.0f64, -1.0,), 0.75 * f64::PI, "atan2 failed",);
+    assert_eq!(Trigonometry::hypot(3.0f32, 4.0,), 5.0, "hypot failed",);
+    assert_eq!(Trigonometry::sin_cos(0.0f32,), (0.0, 1.0,), "sin_cos failed",);
+    assert_approx_eq!(Trigonometry::to_radians(180.0f64,), f64::PI, "to_radians failed",);
+    assert_approx_eq!(Trigonometry::to_degrees(f64::TAU,), 360.0, "to_degrees failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_number_fixed() {
+    use fixed_point::{Fixed32, Fixed64, consts::U16,};
+
+    assert_eq!(Fixed32::<U16>::ZERO, Fixed32::from(0,), "Fixed32 ZERO failed",);
+    assert_eq!(Fixed32::<U16>::ONE, Fixed32::from(1,), "Fixed32 ONE failed",);
+    assert_eq!(Fixed64::<U16>::NEG_ONE, Fixed64::from(-1,), "Fixed64 NEG_ONE failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_floor_fixed() {
+    use fixed_point::{Fixed32, Fixed64, consts::U16,};
+
+    assert_eq!(Fixed32::<U16>::from(2.5,).floor(), 2, "Fixed32 floor failed",);
+    assert_eq!(Fixed64::<U16>::from(-2.5,).floor(), -3, "Fixed64 floor failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_trigonometry_fixed() {
+    use fixed_point::{Fixed32, Fixed64, consts::U16,};
+
+    assert_approx_eq!(Fixed32::<U16>::PI, Fixed32::from(std::f32::consts::PI,), "Fixed32 PI failed",);
+    assert_approx_eq!(Fixed64::<U16>::TAU, Fixed64::from(std::f64::consts::PI * 2.0,), "Fixed64 TAU failed",);
+    assert_approx_eq!(Fixed32::<U16>::from(1,).atan2(Fixed32::from(1,),), Fixed32::PI / 4, "Fixed32 atan2 failed",);
+    assert_approx_eq!(Fixed64::<U16>::from(90,).to_radians(), Fixed64::PI / 2, "Fixed64 to_radians failed",);
+  }
 }
@@ -0,0 +1,210 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::ops;
+
+/// A position in 3D space.
+/// 
+/// Unlike a [Vector] a Point has no direction or length: subtracting two Points gives
+/// the [Vector] between them and adding a [Vector] to a Point moves it, but Points
+/// cannot be added together.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+#[repr(C,)]
+pub struct Point<Num,> {
+  /// The coordinate in the x dimention.
+  pub x: Num,
+  /// The coordinate in the y dimention.
+  pub y: Num,
+  /// The coordinate in the z dimention.
+  pub z: Num,
+}
+
+impl<Num,> Point<Num,> {
+  /// Builds a new Point value.
+  #[inline]
+  pub const fn new(x: Num, y: Num, z: Num,) -> Self { Self { x, y, z, } }
+  /// Returns the Point displaced from the origin by `vector`.
+  #[inline]
+  pub fn from_vector(vector: Vector<Num,>,) -> Self { Self::new(vector.x, vector.y, vector.z,) }
+  /// Returns the displacement of this Point from the origin.
+  #[inline]
+  pub fn to_vector(self,) -> Vector<Num,> { Vector::new(self.x, self.y, self.z,) }
+}
+
+impl<Num: Number,> Point<Num,> {
+  /// The origin of the coordinate system.
+  pub const ORIGIN: Self = Self::new(Num::ZERO, Num::ZERO, Num::ZERO,);
+}
+
+impl<Num: Number + Clone,> Point<Num,> {
+  /// Returns the square of the distance between two Points.
+  #[inline]
+  pub fn distance_squared(lhs: Self, rhs: Self,) -> Num { (lhs - rhs).magnitude_squared() }
+  /// Linearly interpolates between this Point and `to`.
+  /// 
+  /// # Params
+  /// 
+  /// to --- The Point to interpolate towards.  
+  /// t --- The interpolation factor, `0` returns this Point and `1` returns `to`.  
+  #[inline]
+  pub fn lerp(self, to: Self, t: Num,) -> Self { self.clone() + ((to - self) * t) }
+  /// Returns the Point at the barycentric coordinates `weights` of a triangle.
+  /// 
+  /// The weights are divided by their sum, which must not be zero.
+  /// 
+  /// # Params
+  /// 
+  /// triangle --- The corners of the triangle.  
+  /// weights --- The weights of the corners.  
+  pub fn barycentric(triangle: [Self; 3], weights: [Num; 3],) -> Self {
+    let [a, b, c,] = triangle;
+    let [u, v, w,] = weights;
+    let sum = u + v.clone() + w.clone();
+    let ab = b - a.clone();
+    let ac = c - a.clone();
+
+    a + (((ab * v) + (ac * w)) / sum)
+  }
+  /// Returns the barycentric coordinates of this Point relative to a triangle.
+  /// 
+  /// The Point is projected onto the plane of the triangle. The triangle must not be
+  /// degenerate.
+  /// 
+  /// # Params
+  /// 
+  /// triangle --- The corners of the triangle.  
+  pub fn to_barycentric(self, triangle: [Self; 3],) -> [Num; 3] {
+    let [a, b, c,] = triangle;
+    let ab = b - a.clone();
+    let ac = c - a.clone();
+    let ap = self - a;
+    let d00 = Vector::dot(ab.clone(), ab.clone(),);
+    let d01 = Vector::dot(ab.clone(), ac.clone(),);
+    let d11 = Vector::dot(ac.clone(), ac.clone(),);
+    let d20 = Vector::dot(ap.clone(), ab,);
+    let d21 = Vector::dot(ap, ac,);
+    let denom = (d00.clone() * d11.clone()) - (d01.clone() * d01.clone());
+    let v = ((d11 * d20.clone()) - (d01.clone() * d21.clone())) / denom.clone();
+    let w = ((d00 * d21) - (d01 * d20)) / denom;
+
+    [Num::ONE - v.clone() - w.clone(), v, w,]
+  }
+  /// Returns the centroid of a collection of Points.
+  /// 
+  /// Returns `None` if there are no Points.
+  pub fn centroid<I,>(points: I,) -> Option<Self>
+    where I: IntoIterator<Item = Self>, {
+    let mut points = points.into_iter();
+    let first = points.next()?;
+    let (count, offset,) = points.fold((1, Vector::ZERO,), |(count, offset,), point,| {
+      (count + 1, offset + (point - first.clone()),)
+    },);
+
+    Some(first + (offset / Num::from_isize(count,)))
+  }
+}
+
+impl<Num: Sqrt + Clone,> Point<Num,> {
+  /// Returns the distance between two Points.
+  #[inline]
+  pub fn distance(lhs: Self, rhs: Self,) -> Num { (lhs - rhs).magnituid() }
+}
+
+impl<Num,> From<(Num, Num, Num,)> for Point<Num,> {
+  #[inline]
+  fn from((x, y, z,): (Num, Num, Num,),) -> Self { Self { x, y, z, } }
+}
+
+impl<Num,> From<[Num; 3]> for Point<Num,> {
+  #[inline]
+  fn from([x, y, z,]: [Num; 3],) -> Self { Self { x, y, z, } }
+}
+
+impl<Num,> From<Point<Num,>> for [Num; 3] {
+  #[inline]
+  fn from(from: Point<Num,>,) -> Self { [from.x, from.y, from.z,] }
+}
+
+impl<Num,> ops::Sub for Point<Num,>
+  where Num: Clone + ops::Sub<Output = Num>, {
+  type Output = Vector<Num,>;
+
+  #[inline]
+  fn sub(self, rhs: Self,) -> Self::Output { self.to_vector() - rhs.to_vector() }
+}
+
+impl<Num,> ops::Add<Vector<Num,>> for Point<Num,>
+  where Num: Clone + ops::Add<Output = Num>, {
+  type Output = Self;
+
+  #[inline]
+  fn add(mut self, rhs: Vector<Num,>,) -> Self::Output { self += rhs; self }
+}
+
+impl<Num,> ops::AddAssign<Vector<Num,>> for Point<Num,>
+  where Num: Clone + ops::Add<Output = Num>, {
+  #[inline]
+  fn add_assign(&mut self, rhs: Vector<Num,>,) {
+    self.x = self.x.clone() + rhs.x;
+    self.y = self.y.clone() + rhs.y;
+    self.z = self.z.clone() + rhs.z;
+  }
+}
+
+impl<Num,> ops::Sub<Vector<Num,>> for Point<Num,>
+  where Num: Clone + ops::Sub<Output = Num>, {
+  type Output = Self;
+
+  #[inline]
+  fn sub(mut self, rhs: Vector<Num,>,) -> Self::Output { self -= rhs; self }
+}
+
+impl<Num,> ops::SubAssign<Vector<Num,>> for Point<Num,>
+  where Num: Clone + ops::Sub<Output = Num>, {
+  #[inline]
+  fn sub_assign(&mut self, rhs: Vector<Num,>,) {
+    self.x = self.x.clone() - rhs.x;
+    self.y = self.y.clone() - rhs.y;
+    self.z = self.z.clone() - rhs.z;
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_point() {
+    let p1 = Point::new(1, 2, 3,);
+    let p2 = Point::new(4, 6, 3,);
+
+    assert_eq!(p2 - p1, Vector::new(3, 4, 0,), "Subtraction failed",);
+    assert_eq!(p1 + Vector::new(3, 4, 0,), p2, "Translation failed",);
+    assert_eq!(p2 - Vector::new(3, 4, 0,), p1, "Negative translation failed",);
+    assert_eq!(Point::distance_squared(p1, p2,), 25, "distance_squared failed",);
+    assert_eq!(Point::from_vector(p1.to_vector(),), p1, "Vector convertion failed",);
+    assert_eq!(Point::<i32,>::ORIGIN + p1.to_vector(), p1, "ORIGIN failed",);
+
+    let p1 = Point::new(0.0, 0.0, 0.0,);
+    let p2 = Point::new(2.0, 0.0, 0.0,);
+    let p3 = Point::new(0.0, 2.0, 0.0,);
+
+    assert_eq!(Point::distance(p1, p2,), 2.0, "distance failed",);
+    assert_eq!(p1.lerp(p2, 0.25,), Point::new(0.5, 0.0, 0.0,), "lerp failed",);
+    assert_eq!(Point::centroid(vec![p1, p2, p3,],), Some(Point::new(2.0 / 3.0, 2.0 / 3.0, 0.0,)), "centroid failed",);
+    assert_eq!(Point::<f32,>::centroid(None,), None, "Empty centroid failed",);
+
+    let triangle = [p1, p2, p3,];
+    let point = Point::barycentric(triangle, [0.5, 0.25, 0.25,],);
+    assert_eq!(point, Point::new(0.5, 0.5, 0.0,), "barycentric failed",);
+    assert_eq!(point.to_barycentric(triangle,), [0.5, 0.25, 0.25,], "to_barycentric failed",);
+    let triangle = triangle.map(|point,| point + Vector::new(1.0, 1.0, 1.0,),);
+    assert_eq!(Point::barycentric(triangle, [0.5, 0.25, 0.25,],), Point::new(1.5, 1.5, 1.0,), "Translated barycentric failed",);
+    assert_eq!(Point::barycentric(triangle, [2.0, 1.0, 1.0,],), Point::new(1.5, 1.5, 1.0,), "Weight sum failed",);
+    assert_approx_eq!(
+      Point::new(0.1, 0.2, 0.3,) + Vector::new(0.2, 0.1, 0.0,), Point::new(0.3, 0.3, 0.3,),
+      "ApproxEq failed",
+    );
+  }
+}
@@ -0,0 +1,739 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+#[cfg(feature = "fixed-point",)]
+use fixed_point::{Fixed32, Fixed64, Unsigned,};
+use core::{ops, iter, cmp::Ordering,};
+
+/// Defines geometric predicates which always return the correct sign.
+/// 
+/// Evaluating these determinants directly, using [Vector::cross] and [Vector::dot],
+/// can round to the wrong sign for nearly degenerate inputs and lead to inconsistent
+/// decisions in meshing and triangulation.
+/// 
+/// Float predicates are adaptive, in the style of Shewchuk's predicates: the
+/// determinant is evaluated in floating point and only falls back to exact arithmetic
+/// when an error bound cannot confirm its sign. The exact fallback sums the exact
+/// products of the coordinates into a single wide integer, so it needs a few kilobytes
+/// of stack at most. Integer and fixed point predicates are always evaluated exactly
+/// using wide intermediates. The result is unspecified for non finite floats.
+pub trait Predicates: Number {
+  /// Returns `Greater` if `a`, `b` and `c` are in counterclockwise order, `Less` if they
+  /// are in clockwise order and `Equal` if they are collinear.
+  /// 
+  /// Only the x and y coordinates are considered.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The first point.  
+  /// b --- The second point.  
+  /// c --- The third point.  
+  fn orient2d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>,) -> Ordering;
+  /// Returns `Greater` if `d` lies below the plane through `a`, `b` and `c`, `Less` if it
+  /// lies above the plane and `Equal` if the four points are coplanar.
+  /// 
+  /// Above is the side of the plane from which `a`, `b` and `c` appear counterclockwise.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The first point of the plane.  
+  /// b --- The second point of the plane.  
+  /// c --- The third point of the plane.  
+  /// d --- The point to test.  
+  fn orient3d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering;
+  /// Returns `Greater` if `d` lies inside the circle through `a`, `b` and `c`, `Less` if
+  /// it lies outside and `Equal` if the four points are cocircular.
+  /// 
+  /// `a`, `b` and `c` must be in counterclockwise order, see [Predicates::orient2d],
+  /// otherwise the result is reversed. Only the x and y coordinates are considered.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The first point of the circle.  
+  /// b --- The second point of the circle.  
+  /// c --- The third point of the circle.  
+  /// d --- The point to test.  
+  fn incircle(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering;
+  /// Returns `Greater` if `e` lies inside the sphere through `a`, `b`, `c` and `d`, `Less`
+  /// if it lies outside and `Equal` if the five points are cospherical.
+  /// 
+  /// [Predicates::orient3d] must return `Greater` for `a`, `b`, `c` and `d`, otherwise the
+  /// result is reversed.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The first point of the sphere.  
+  /// b --- The second point of the sphere.  
+  /// c --- The third point of the sphere.  
+  /// d --- The fourth point of the sphere.  
+  /// e --- The point to test.  
+  fn insphere(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>, e: Vector<Self,>,) -> Ordering;
+}
+
+impl Predicates for f64 {
+  fn orient2d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>,) -> Ordering {
+    adaptive::<Orient2d, { limbs(FLOAT_BITS, 2,) }, 2,>([flat(a,), flat(b,),], flat(c,),)
+  }
+  fn orient3d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+    adaptive::<Orient3d, { limbs(FLOAT_BITS, 3,) }, 3,>([a, b, c,], d,)
+  }
+  fn incircle(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+    adaptive::<InCircle, { limbs(FLOAT_BITS, 4,) }, 3,>([flat(a,), flat(b,), flat(c,),], flat(d,),)
+  }
+  fn insphere(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>, e: Vector<Self,>,) -> Ordering {
+    adaptive::<InSphere, { limbs(FLOAT_BITS, 5,) }, 4,>([a, b, c, d,], e,)
+  }
+}
+
+/// Every f32 is exactly representable as an f64 so the f64 predicates are used.
+impl Predicates for f32 {
+  fn orient2d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>,) -> Ordering {
+    f64::orient2d(a.map(f64::from,), b.map(f64::from,), c.map(f64::from,),)
+  }
+  fn orient3d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+    f64::orient3d(a.map(f64::from,), b.map(f64::from,), c.map(f64::from,), d.map(f64::from,),)
+  }
+  fn incircle(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+    f64::incircle(a.map(f64::from,), b.map(f64::from,), c.map(f64::from,), d.map(f64::from,),)
+  }
+  fn insphere(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>, e: Vector<Self,>,) -> Ordering {
+    f64::insphere(a.map(f64::from,), b.map(f64::from,), c.map(f64::from,), d.map(f64::from,), e.map(f64::from,),)
+  }
+}
+
+macro_rules! impl_integer {
+  ($($num:ty,)*) => {
+    $(
+      impl Predicates for $num {
+        fn orient2d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>,) -> Ordering {
+          exact::<Orient2d, { limbs(<$num>::BITS as usize, 2,) }, 2, _,>([flat(a,), flat(b,),], flat(c,), |num,| Wide::new(num as i128,),)
+        }
+        fn orient3d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+          exact::<Orient3d, { limbs(<$num>::BITS as usize, 3,) }, 3, _,>([a, b, c,], d, |num,| Wide::new(num as i128,),)
+        }
+        fn incircle(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+          exact::<InCircle, { limbs(<$num>::BITS as usize, 4,) }, 3, _,>([flat(a,), flat(b,), flat(c,),], flat(d,), |num,| Wide::new(num as i128,),)
+        }
+        fn insphere(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>, e: Vector<Self,>,) -> Ordering {
+          exact::<InSphere, { limbs(<$num>::BITS as usize, 5,) }, 4, _,>([a, b, c, d,], e, |num,| Wide::new(num as i128,),)
+        }
+      }
+    )*
+  };
+}
+
+impl_integer!(isize, i8, i16, i32, i64, i128,);
+
+/// The predicates are evaluated exactly on the raw bits, scaling every coordinate by the
+/// same positive amount does not change the sign of a determinant.
+/// 
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> Predicates for Fixed32<Shift,> {
+  fn orient2d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>,) -> Ordering {
+    i32::orient2d(a.map(Self::to_bits,), b.map(Self::to_bits,), c.map(Self::to_bits,),)
+  }
+  fn orient3d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+    i32::orient3d(a.map(Self::to_bits,), b.map(Self::to_bits,), c.map(Self::to_bits,), d.map(Self::to_bits,),)
+  }
+  fn incircle(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+    i32::incircle(a.map(Self::to_bits,), b.map(Self::to_bits,), c.map(Self::to_bits,), d.map(Self::to_bits,),)
+  }
+  fn insphere(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>, e: Vector<Self,>,) -> Ordering {
+    i32::insphere(a.map(Self::to_bits,), b.map(Self::to_bits,), c.map(Self::to_bits,), d.map(Self::to_bits,), e.map(Self::to_bits,),)
+  }
+}
+
+/// The predicates are evaluated exactly on the raw bits, scaling every coordinate by the
+/// same positive amount does not change the sign of a determinant.
+/// 
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> Predicates for Fixed64<Shift,> {
+  fn orient2d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>,) -> Ordering {
+    i64::orient2d(a.map(Self::to_bits,), b.map(Self::to_bits,), c.map(Self::to_bits,),)
+  }
+  fn orient3d(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+    i64::orient3d(a.map(Self::to_bits,), b.map(Self::to_bits,), c.map(Self::to_bits,), d.map(Self::to_bits,),)
+  }
+  fn incircle(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>,) -> Ordering {
+    i64::incircle(a.map(Self::to_bits,), b.map(Self::to_bits,), c.map(Self::to_bits,), d.map(Self::to_bits,),)
+  }
+  fn insphere(a: Vector<Self,>, b: Vector<Self,>, c: Vector<Self,>, d: Vector<Self,>, e: Vector<Self,>,) -> Ordering {
+    i64::insphere(a.map(Self::to_bits,), b.map(Self::to_bits,), c.map(Self::to_bits,), d.map(Self::to_bits,), e.map(Self::to_bits,),)
+  }
+}
+
+/// The bits needed for the magnitude of a finite f64 once it is scaled to an integer.
+const FLOAT_BITS: usize = 2100;
+
+/// The number of limbs needed to evaluate a determinant exactly.
+/// 
+/// # Params
+/// 
+/// bits --- The bits needed for the magnitude of a coordinate.  
+/// degree --- The degree of the determinant.  
+const fn limbs(bits: usize, degree: usize,) -> usize { (degree * (bits + 1) + 8) / 64 + 1 }
+
+/// Projects a point onto the xy plane.
+#[inline]
+fn flat<Num: Number,>(vector: Vector<Num,>,) -> Vector<Num,> { Vector::new(vector.x, vector.y, Num::ZERO,) }
+
+/// Evaluates a determinant, using floating point if the sign can be trusted.
+/// 
+/// # Params
+/// 
+/// points --- The points of the determinant.  
+/// origin --- The point all other points are measured from.  
+fn adaptive<D, const N: usize, const P: usize,>(points: [Vector<f64,>; P], origin: Vector<f64,>,) -> Ordering
+  where D: Determinant<P,>, {
+  let det = D::det(points.map(|point,| (point - origin).map(Filter::new,),),);
+  let bound = D::ERROR * det.magnitude;
+
+  if det.value > bound { Ordering::Greater }
+  else if -det.value > bound { Ordering::Less }
+  else { exact_float::<D, N, P,>(points, origin,) }
+}
+
+/// Evaluates a determinant of f64 coordinates exactly.
+/// 
+/// # Params
+/// 
+/// points --- The points of the determinant.  
+/// origin --- The point all other points are measured from.  
+fn exact_float<D, const N: usize, const P: usize,>(points: [Vector<f64,>; P], origin: Vector<f64,>,) -> Ordering
+  where D: Determinant<P,>, {
+  //Scale every coordinate by the smallest exponent so every product is an integer.
+  let scale = points.iter().chain(iter::once(&origin,),)
+    .flat_map(|vector,| vector.iter().copied(),)
+    .map(split,)
+    .filter(|&(mantissa, _,),| mantissa != 0,)
+    .map(|(_, exponent,),| exponent,)
+    .min().unwrap_or(0,);
+  //Measuring from the origin could overflow, instead the origin becomes a row of the
+  //determinant with a column of ones.
+  let mut rows = [[Entry::One; 5]; 5];
+
+  for (row, point,) in rows.iter_mut().zip(points.iter().chain(iter::once(&origin,),),) {
+    row[..P].copy_from_slice(&D::row(*point,),);
+  }
+
+  let mut sum = Exact::<N,> { limbs: [0; N], scale, };
+
+  sum.expand(&rows[..P + 1], 0, 0, false, &mut [0.0; 5], 0,);
+  sum.signum()
+}
+
+/// Evaluates a determinant exactly.
+/// 
+/// # Params
+/// 
+/// points --- The points of the determinant.  
+/// origin --- The point all other points are measured from.  
+/// wide --- Converts a coordinate to an integer.  
+fn exact<D, const N: usize, const P: usize, Num: Copy,>(points: [Vector<Num,>; P], origin: Vector<Num,>, wide: impl Fn(Num,) -> Wide<N,>,) -> Ordering
+  where D: Determinant<P,>, {
+  let origin = origin.map(&wide,);
+
+  D::det(points.map(|point,| point.map(&wide,) - origin,),).signum()
+}
+
+/// Splits a finite f64 into an integer mantissa and a binary exponent.
+fn split(num: f64,) -> (i64, i32,) {
+  let bits = num.to_bits();
+  let exponent = ((bits >> 52) & 0x7FF) as i32;
+  let fraction = (bits & ((1 << 52) - 1)) as i64;
+  let (mantissa, exponent,) = if exponent == 0 { (fraction, -1074,) }
+    else { (fraction | (1 << 52), exponent - 1075,) };
+
+  (if num.is_sign_negative() { -mantissa } else { mantissa }, exponent,)
+}
+
+/// The arithmetic needed to evaluate a determinant.
+trait Ring: Copy + ops::Add<Output = Self> + ops::Sub<Output = Self> + ops::Mul<Output = Self> {}
+
+impl<T,> Ring for T
+  where T: Copy + ops::Add<Output = Self> + ops::Sub<Output = Self> + ops::Mul<Output = Self> {}
+
+/// A determinant of points measured from a common origin.
+trait Determinant<const P: usize,> {
+  /// Bounds the rounding error of the determinant evaluated in f64, relative to its
+  /// magnitude.
+  const ERROR: f64;
+
+  /// Evaluates the determinant.
+  /// 
+  /// # Params
+  /// 
+  /// points --- The points measured from the origin.  
+  fn det<T: Ring,>(points: [Vector<T,>; P],) -> T;
+  /// Returns the row of `point` in the equivalent determinant which is not measured from
+  /// the origin, without its final column of ones.
+  /// 
+  /// # Params
+  /// 
+  /// point --- The point of the row.  
+  fn row(point: Vector<f64,>,) -> [Entry; P];
+}
+
+/// Half the distance from 1 to the next f64.
+const EPSILON: f64 = f64::EPSILON / 2.0;
+
+/// The determinant of [Predicates::orient2d].
+struct Orient2d;
+
+impl Determinant<2,> for Orient2d {
+  const ERROR: f64 = (3.0 + (16.0 * EPSILON)) * EPSILON;
+
+  fn det<T: Ring,>([a, b,]: [Vector<T,>; 2],) -> T { (a.x * b.y) - (a.y * b.x) }
+  fn row(point: Vector<f64,>,) -> [Entry; 2] { [Entry::Coordinate(point.x,), Entry::Coordinate(point.y,),] }
+}
+
+/// The determinant of [Predicates::orient3d].
+struct Orient3d;
+
+impl Determinant<3,> for Orient3d {
+  const ERROR: f64 = (7.0 + (56.0 * EPSILON)) * EPSILON;
+
+  fn det<T: Ring,>([a, b, c,]: [Vector<T,>; 3],) -> T {
+    (a.z * ((b.x * c.y) - (c.x * b.y)))
+    + (b.z * ((c.x * a.y) - (a.x * c.y)))
+    + (c.z * ((a.x * b.y) - (b.x * a.y)))
+  }
+  fn row(point: Vector<f64,>,) -> [Entry; 3] { point.map(Entry::Coordinate,).into() }
+}
+
+/// The determinant of [Predicates::incircle].
+struct InCircle;
+
+impl Determinant<3,> for InCircle {
+  const ERROR: f64 = (10.0 + (96.0 * EPSILON)) * EPSILON;
+
+  fn det<T: Ring,>([a, b, c,]: [Vector<T,>; 3],) -> T {
+    let lift = |point: Vector<T,>,| (point.x * point.x) + (point.y * point.y);
+
+    (lift(a,) * ((b.x * c.y) - (c.x * b.y)))
+    + (lift(b,) * ((c.x * a.y) - (a.x * c.y)))
+    + (lift(c,) * ((a.x * b.y) - (b.x * a.y)))
+  }
+  fn row(point: Vector<f64,>,) -> [Entry; 3] {
+    [Entry::Coordinate(point.x,), Entry::Coordinate(point.y,), Entry::Lift(point,),]
+  }
+}
+
+/// The determinant of [Predicates::insphere].
+struct InSphere;
+
+impl Determinant<4,> for InSphere {
+  const ERROR: f64 = (16.0 + (224.0 * EPSILON)) * EPSILON;
+
+  fn det<T: Ring,>([a, b, c, d,]: [Vector<T,>; 4],) -> T {
+    let lift = |point: Vector<T,>,| (point.x * point.x) + (point.y * point.y) + (point.z * point.z);
+    let ab = (a.x * b.y) - (b.x * a.y);
+    let bc = (b.x * c.y) - (c.x * b.y);
+    let cd = (c.x * d.y) - (d.x * c.y);
+    let da = (d.x * a.y) - (a.x * d.y);
+    let ac = (a.x * c.y) - (c.x * a.y);
+    let bd = (b.x * d.y) - (d.x * b.y);
+    let abc = (a.z * bc) - (b.z * ac) + (c.z * ab);
+    let bcd = (b.z * cd) - (c.z * bd) + (d.z * bc);
+    let cda = (c.z * da) + (d.z * ac) + (a.z * cd);
+    let dab = (d.z * ab) + (a.z * bd) + (b.z * da);
+
+    ((lift(d,) * abc) - (lift(c,) * dab)) + ((lift(b,) * cda) - (lift(a,) * bcd))
+  }
+  fn row(point: Vector<f64,>,) -> [Entry; 4] {
+    [Entry::Coordinate(point.x,), Entry::Coordinate(point.y,), Entry::Coordinate(point.z,), Entry::Lift(point,),]
+  }
+}
+
+/// An entry of a determinant which is not measured from the origin.
+#[derive(Clone, Copy,)]
+enum Entry {
+  /// The constant 1.
+  One,
+  /// A coordinate of a point.
+  Coordinate(f64,),
+  /// The sum of the squares of the coordinates of a point.
+  Lift(Vector<f64,>,),
+}
+
+/// An exact sum of products of f64s.
+/// 
+/// Every coordinate is divided by `2^scale` so each product is an integer, the sum is a
+/// two's complement integer of `N` 64 bit limbs, least significant first.
+struct Exact<const N: usize,> {
+  /// The limbs of the sum.
+  limbs: [u64; N],
+  /// The smallest exponent of the coordinates.
+  scale: i32,
+}
+
+impl<const N: usize,> Exact<N,> {
+  /// Adds each product of the Leibniz expansion of the determinant of `rows`, starting
+  /// from `row`.
+  /// 
+  /// # Params
+  /// 
+  /// rows --- The rows of the determinant.  
+  /// row --- The row to choose a column from.  
+  /// used --- The columns chosen for the previous rows.  
+  /// negative --- `true` if the permutation so far is odd.  
+  /// factors --- The factors chosen for the previous rows.  
+  /// len --- The number of factors chosen.  
+  fn expand(&mut self, rows: &[[Entry; 5]], row: usize, used: usize, negative: bool, factors: &mut [f64; 5], len: usize,) {
+    if row == rows.len() { return self.add(&factors[..len], negative,) }
+
+    for column in (0..rows.len()).filter(|column,| used & (1 << column) == 0,) {
+      //Each unused column before this one is an inversion of the permutation.
+      let negative = negative ^ ((!used & ((1 << column) - 1)).count_ones() % 2 == 1);
+      let used = used | (1 << column);
+
+      match rows[row][column] {
+        Entry::One => self.expand(rows, row + 1, used, negative, factors, len,),
+        Entry::Coordinate(coordinate,) => {
+          factors[len] = coordinate;
+          self.expand(rows, row + 1, used, negative, factors, len + 1,);
+        },
+        Entry::Lift(point,) => for &coordinate in point.iter() {
+          factors[len] = coordinate;
+          factors[len + 1] = coordinate;
+          self.expand(rows, row + 1, used, negative, factors, len + 2,);
+        },
+      }
+    }
+  }
+  /// Adds the exact product of `factors` to the sum.
+  /// 
+  /// # Params
+  /// 
+  /// factors --- The factors of the product, at most 5.  
+  /// negative --- `true` if the product is subtracted.  
+  fn add(&mut self, factors: &[f64], mut negative: bool,) {
+    //Five 53 bit mantissas fit in five limbs.
+    let mut product = [1, 0, 0, 0, 0, 0,];
+    let mut shift = 0;
+
+    for &factor in factors {
+      let (mantissa, exponent,) = split(factor,);
+
+      if mantissa == 0 { return }
+
+      let mut carry = 0;
+
+      for limb in product.iter_mut() {
+        let wide = (*limb as u128 * mantissa.unsigned_abs() as u128) + carry;
+
+        *limb = wide as u64;
+        carry = wide >> 64;
+      }
+
+      negative ^= mantissa < 0;
+      shift += (exponent - self.scale) as usize;
+    }
+
+    let (offset, bits,) = (shift / 64, shift % 64,);
+    let mut carry = false;
+
+    for (index, limb,) in self.limbs.iter_mut().enumerate().skip(offset,) {
+      let index = index - offset;
+
+      if index > product.len() && !carry { break }
+
+      let mut part = product.get(index,).map_or(0, |&part,| part << bits,);
+
+      if bits > 0 && index > 0 && index <= product.len() { part |= product[index - 1] >> (64 - bits) }
+
+      let (sum, carry1,) = if negative { limb.overflowing_sub(part,) } else { limb.overflowing_add(part,) };
+      let (sum, carry2,) = if negative { sum.overflowing_sub(carry as u64,) } else { sum.overflowing_add(carry as u64,) };
+
+      *limb = sum;
+      carry = carry1 || carry2;
+    }
+  }
+  /// Returns the sign of the sum.
+  fn signum(&self,) -> Ordering {
+    if self.limbs[N - 1] >> 63 == 1 { Ordering::Less }
+    else if self.limbs.iter().all(|&limb,| limb == 0,) { Ordering::Equal }
+    else { Ordering::Greater }
+  }
+}
+
+/// An f64 paired with the sum of the magnitudes of the terms which produced it.
+/// 
+/// The rounding error of the value is bounded relative to the magnitude.
+#[derive(Clone, Copy,)]
+struct Filter {
+  /// The rounded value.
+  value: f64,
+  /// The rounded sum of the magnitudes of the terms.
+  magnitude: f64,
+}
+
+impl Filter {
+  /// Creates a new Filter from an exact value.
+  #[inline]
+  fn new(value: f64,) -> Self { Self { value, magnitude: if value < 0.0 { -value } else { value }, } }
+}
+
+impl ops::Add for Filter {
+  type Output = Self;
+
+  #[inline]
+  fn add(self, rhs: Self,) -> Self::Output {
+    Self { value: self.value + rhs.value, magnitude: self.magnitude + rhs.magnitude, }
+  }
+}
+
+impl ops::Sub for Filter {
+  type Output = Self;
+
+  #[inline]
+  fn sub(self, rhs: Self,) -> Self::Output {
+    Self { value: self.value - rhs.value, magnitude: self.magnitude + rhs.magnitude, }
+  }
+}
+
+impl ops::Mul for Filter {
+  type Output = Self;
+
+  #[inline]
+  fn mul(self, rhs: Self,) -> Self::Output {
+    Self { value: self.value * rhs.value, magnitude: self.magnitude * rhs.magnitude, }
+  }
+}
+
+/// A two's complement integer of `N` 64 bit limbs, least significant first.
+/// 
+/// Arithmetic wraps on overflow, callers size `N` so results always fit.
+#[derive(Clone, Copy,)]
+struct Wide<const N: usize,>([u64; N],);
+
+impl<const N: usize,> Wide<N,> {
+  /// Creates a new Wide integer.
+  fn new(value: i128,) -> Self {
+    let mut limbs = [if value < 0 { u64::MAX } else { 0 }; N];
+
+    limbs[0] = value as u64;
+    if N > 1 { limbs[1] = (value >> 64) as u64 }
+
+    Self(limbs,)
+  }
+  /// Returns `true` if this integer is negative.
+  #[inline]
+  fn is_negative(&self,) -> bool { self.0[N - 1] >> 63 == 1 }
+  /// Returns the absolute value of this integer.
+  #[inline]
+  fn abs(self,) -> Self { if self.is_negative() { -self } else { self } }
+  /// Returns the number of limbs up to the most significant non zero limb.
+  #[inline]
+  fn len(&self,) -> usize { self.0.iter().rposition(|&limb,| limb != 0,).map_or(0, |index,| index + 1,) }
+  /// Returns the sign of this integer.
+  fn signum(&self,) -> Ordering {
+    if self.is_negative() { Ordering::Less }
+    else if self.len() == 0 { Ordering::Equal }
+    else { Ordering::Greater }
+  }
+}
+
+impl<const N: usize,> ops::Neg for Wide<N,> {
+  type Output = Self;
+
+  fn neg(self,) -> Self::Output {
+    let mut negated = self.0.map(|limb,| !limb,);
+
+    for limb in negated.iter_mut() {
+      let (sum, carry,) = limb.overflowing_add(1,);
+
+      *limb = sum;
+      if !carry { break }
+    }
+
+    Self(negated,)
+  }
+}
+
+impl<const N: usize,> ops::Add for Wide<N,> {
+  type Output = Self;
+
+  fn add(mut self, rhs: Self,) -> Self::Output {
+    let mut carry = false;
+
+    for (lhs, rhs,) in self.0.iter_mut().zip(rhs.0.iter(),) {
+      let (sum, carry1,) = lhs.overflowing_add(*rhs,);
+      let (sum, carry2,) = sum.overflowing_add(carry as u64,);
+
+      *lhs = sum;
+      carry = carry1 || carry2;
+    }
+
+    self
+  }
+}
+
+impl<const N: usize,> ops::Sub for Wide<N,> {
+  type Output = Self;
+
+  #[inline]
+  fn sub(self, rhs: Self,) -> Self::Output { self + -rhs }
+}
+
+impl<const N: usize,> ops::Mul for Wide<N,> {
+  type Output = Self;
+
+  fn mul(self, rhs: Self,) -> Self::Output {
+    //Multiplying magnitudes only visits the significant limbs.
+    let negative = self.is_negative() != rhs.is_negative();
+    let (lhs, rhs,) = (self.abs(), rhs.abs(),);
+    let rhs_len = rhs.len();
+    let mut product = [0; N];
+
+    for (index, &lhs,) in lhs.0[..lhs.len()].iter().enumerate() {
+      let mut carry = 0;
+
+      for (offset, &rhs,) in rhs.0[..rhs_len.min(N - index,)].iter().enumerate() {
+        let sum = (lhs as u128 * rhs as u128) + product[index + offset] as u128 + carry;
+
+        product[index + offset] = sum as u64;
+        carry = sum >> 64;
+      }
+
+      if index + rhs_len < N { product[index + rhs_len] = carry as u64 }
+    }
+
+    let product = Self(product,);
+
+    if negative { -product } else { product }
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_predicates() {
+    let (a, b, c,) = (Vector::new(0.0, 0.0, 0.0,), Vector::new(1.0, 0.0, 0.0,), Vector::new(0.0, 1.0, 0.0,),);
+    assert_eq!(f64::orient2d(a, b, c,), Ordering::Greater, "orient2d failed 1",);
+    assert_eq!(f64::orient2d(a, c, b,), Ordering::Less, "orient2d failed 2",);
+    assert_eq!(f64::orient2d(a, b, b * 2.0,), Ordering::Equal, "orient2d failed 3",);
+
+    let d = Vector::new(0.0, 0.0, 1.0,);
+    assert_eq!(f64::orient3d(a, b, c, -d,), Ordering::Greater, "orient3d failed 1",);
+    assert_eq!(f64::orient3d(a, b, c, d,), Ordering::Less, "orient3d failed 2",);
+    assert_eq!(f64::orient3d(a, b, c, b + c,), Ordering::Equal, "orient3d failed 3",);
+
+    let inside = Vector::new(0.25, 0.25, 0.0,);
+    assert_eq!(f64::incircle(a, b, c, inside,), Ordering::Greater, "incircle failed 1",);
+    assert_eq!(f64::incircle(a, b, c, b * 2.0,), Ordering::Less, "incircle failed 2",);
+    assert_eq!(f64::incircle(a, b, c, b + c,), Ordering::Equal, "incircle failed 3",);
+    assert_eq!(f64::incircle(a, c, b, inside,), Ordering::Less, "incircle failed 4",);
+
+    let (a, b, c, d,) = (d, b, c, -d,);
+    assert_eq!(f64::orient3d(a, b, c, d,), Ordering::Greater, "insphere setup failed",);
+    assert_eq!(f64::insphere(a, b, c, d, Vector::new(0.0, 0.0, 0.5,),), Ordering::Greater, "insphere failed 1",);
+    assert_eq!(f64::insphere(a, b, c, d, Vector::new(2.0, 0.0, 0.0,),), Ordering::Less, "insphere failed 2",);
+    assert_eq!(f64::insphere(a, b, c, d, Vector::new(-1.0, 0.0, 0.0,),), Ordering::Equal, "insphere failed 3",);
+  }
+  #[test]
+  fn test_predicates_degenerate() {
+    //Points on the line `y = x` which rounding pushes to either side.
+    let (a, b,) = (Vector::new(12.0, 12.0, 0.0,), Vector::new(24.0, 24.0, 0.0,),);
+    let mut naive = [0; 3];
+
+    for x in 0..64 {
+      for y in 0..64 {
+        let c = Vector::new(0.5 + (x as f64 * f64::EPSILON), 0.5 + (y as f64 * f64::EPSILON), 0.0,);
+        let expected = y.cmp(&x,);
+        let cross = ((b.x - a.x) * (c.y - a.y)) - ((b.y - a.y) * (c.x - a.x));
+
+        naive[(cross.partial_cmp(&0.0,).unwrap() as i8 + 1) as usize] += 1;
+        assert_eq!(f64::orient2d(a, b, c,), expected, "orient2d failed",);
+      }
+    }
+    assert_ne!(naive, [64 * 63 / 2, 64, 64 * 63 / 2,], "The naive determinant was exact",);
+
+    let (a, b,) = (a.map(|num,| num as f32,), b.map(|num,| num as f32,),);
+    let c = Vector::new(0.5 + f32::EPSILON, 0.5, 0.0,);
+    assert_eq!(f32::orient2d(a, b, c,), Ordering::Less, "f32 orient2d failed",);
+
+    //Cocircular and cospherical points far from the origin.
+    let offset = Vector::new(1e15, -3e14, 7e14,);
+    let circle = [Vector::new(1.0, 0.0, 0.0,), Vector::new(0.0, 1.0, 0.0,), Vector::new(-1.0, 0.0, 0.0,),];
+    let [a, b, c,] = circle.map(|point,| point * 0.75 + offset,);
+    let d = Vector::new(0.0, -0.75, 0.0,) + offset;
+    assert_eq!(f64::incircle(a, b, c, d,), Ordering::Equal, "incircle failed",);
+    assert_eq!(f64::insphere(Vector::new(0.0, 0.0, -0.75,) + offset, a, b, c, Vector::new(0.0, 0.0, 0.75,) + offset,), Ordering::Equal, "insphere failed",);
+
+    //Coordinates whose exponents are far apart.
+    let (a, b,) = (Vector::new(1e-300, 0.0, 0.0,), Vector::new(1e300, 1e300, 0.0,),);
+    assert_eq!(f64::orient2d(a, b, b * 2.0,), Ordering::Less, "orient2d failed 1",);
+    assert_eq!(f64::orient2d(a, b * 2.0, b,), Ordering::Greater, "orient2d failed 2",);
+  }
+  #[test]
+  fn test_predicates_exact() {
+    //Compare the exact float fallback against the integer predicates on points clustered
+    //far from the origin, which are exact as f64.
+    let mut state = 7u64;
+    let mut vector = || {
+      let mut next = || {
+        state = state.wrapping_mul(6364136223846793005,).wrapping_add(1442695040888963407,);
+        (state >> 54) as i64 - 512 + (1 << 40)
+      };
+
+      Vector::new(next(), next(), next(),)
+    };
+
+    for _ in 0..256 {
+      let [a, b, c, d, e,] = [vector(), vector(), vector(), vector(), vector(),];
+      let float = |vector: Vector<i64,>,| vector.map(|num,| num as f64 * 0.375,);
+      let flat = |vector: Vector<i64,>,| flat(float(vector,),);
+
+      assert_eq!(exact_float::<Orient2d, { limbs(FLOAT_BITS, 2,) }, 2,>([flat(a,), flat(b,),], flat(c,),), i64::orient2d(a, b, c,), "orient2d failed",);
+      assert_eq!(exact_float::<Orient3d, { limbs(FLOAT_BITS, 3,) }, 3,>([float(a,), float(b,), float(c,),], float(d,),), i64::orient3d(a, b, c, d,), "orient3d failed",);
+      assert_eq!(exact_float::<InCircle, { limbs(FLOAT_BITS, 4,) }, 3,>([flat(a,), flat(b,), flat(c,),], flat(d,),), i64::incircle(a, b, c, d,), "incircle failed",);
+      assert_eq!(exact_float::<InSphere, { limbs(FLOAT_BITS, 5,) }, 4,>([float(a,), float(b,), float(c,), float(d,),], float(e,),), i64::insphere(a, b, c, d, e,), "insphere failed",);
+    }
+
+    //The exact fallback fits in a small stack.
+    std::thread::Builder::new().stack_size(32 * 1024,).spawn(|| {
+      let offset = Vector::new(1e300, -1e-300, 7e14,);
+      let sphere = [Vector::Z, Vector::X, Vector::Y, Vector::NEG_Z, Vector::NEG_X,].map(|point,| point * 0.75 + offset,);
+      let [a, b, c, d, e,] = sphere;
+
+      f64::insphere(a, b, c, d, e,)
+    },).expect("Spawn failed").join().expect("insphere overflowed the stack");
+  }
+  #[test]
+  fn test_predicates_integer() {
+    let (a, b, c,) = (Vector::new(i64::MIN, i64::MIN, 0,), Vector::new(i64::MAX, i64::MAX, 0,), Vector::new(0, 1, 0,),);
+    assert_eq!(i64::orient2d(a, b, c,), Ordering::Greater, "i64 orient2d failed 1",);
+    assert_eq!(i64::orient2d(a, b, Vector::new(-1, -1, 0,),), Ordering::Equal, "i64 orient2d failed 2",);
+
+    let big = i128::MAX / 2;
+    let (a, b, c, d,) = (Vector::new(0, 0, big,), Vector::new(big, 0, 0,), Vector::new(0, big, 0,), Vector::new(0, 0, -big,),);
+    assert_eq!(i128::orient3d(a, b, c, d,), Ordering::Greater, "i128 orient3d failed",);
+    assert_eq!(i128::insphere(a, b, c, d, Vector::new(-big, 0, 0,),), Ordering::Equal, "i128 insphere failed 1",);
+    assert_eq!(i128::insphere(a, b, c, d, Vector::new(1 - big, 0, 0,),), Ordering::Greater, "i128 insphere failed 2",);
+    assert_eq!(i128::incircle(b, c, -b, Vector::new(0, -big - 1, 0,),), Ordering::Less, "i128 incircle failed",);
+
+    let (a, b, c,) = (Vector::new(0i8, 0, 0,), Vector::new(127, -128, 0,), Vector::new(-128, 127, 0,),);
+    assert_eq!(i8::orient2d(a, b, c,), Ordering::Less, "i8 orient2d failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_predicates_fixed() {
+    use fixed_point::consts::U16;
+
+    let vector = |x: f32, y: f32, z: f32,| Vector::<Fixed32<U16>>::new(x.into(), y.into(), z.into(),);
+    let (a, b, c,) = (vector(0.0, 0.0, 0.0,), vector(1.0, 0.0, 0.0,), vector(0.0, 1.0, 0.0,),);
+    assert_eq!(Fixed32::orient2d(a, b, c,), Ordering::Greater, "Fixed32 orient2d failed",);
+    assert_eq!(Fixed32::incircle(a, b, c, vector(1.0, 1.0, 0.0,),), Ordering::Equal, "Fixed32 incircle failed",);
+    assert_eq!(Fixed32::orient3d(a, b, c, vector(0.0, 0.0, -0.5,),), Ordering::Greater, "Fixed32 orient3d failed",);
+
+    let vector = |x: f64, y: f64, z: f64,| Vector::<Fixed64<U16>>::new(x.into(), y.into(), z.into(),);
+    let (a, b, c, d,) = (vector(0.0, 0.0, 1.0,), vector(1.0, 0.0, 0.0,), vector(0.0, 1.0, 0.0,), vector(0.0, 0.0, -1.0,),);
+    assert_eq!(Fixed64::insphere(a, b, c, d, vector(0.0, -1.0, 0.0,),), Ordering::Equal, "Fixed64 insphere failed",);
+  }
+}
@@ -0,0 +1,232 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+
+/// The multiplier of the PCG linear congruential step.
+const MULTIPLIER: u64 = 6364136223846793005;
+
+/// A seedable pseudo random number generator (PCG32, XSH RR variant).
+/// 
+/// The same seed always produces the same sequence on every platform. The samplers
+/// only use the arithmetic of the [Number] type and [Sqrt], which is correctly rounded
+/// on every platform, so fixed point samples are reproducible from a seed. The only
+/// exception is [Rng::rotation], whose angle needs [Trigonometry]; [Rng::rotor] samples
+/// the same distribution reproducibly.
+#[derive(PartialEq, Eq, Clone, Debug, Hash,)]
+pub struct Rng {
+  /// The internal state.
+  state: u64,
+  /// The stream selector, always odd.
+  increment: u64,
+}
+
+impl Rng {
+  /// Creates a new Rng from a seed.
+  /// 
+  /// # Params
+  /// 
+  /// seed --- The seed of the sequence.  
+  #[inline]
+  pub fn new(seed: u64,) -> Self { Self::with_stream(seed, 0,) }
+  /// Creates a new Rng from a seed and a stream.
+  /// 
+  /// Different streams produce independent sequences from the same seed.
+  /// 
+  /// # Params
+  /// 
+  /// seed --- The seed of the sequence.  
+  /// stream --- The stream of the sequence.  
+  pub fn with_stream(seed: u64, stream: u64,) -> Self {
+    let mut rng = Self { state: 0, increment: (stream << 1) | 1, };
+
+    rng.next_u32();
+    rng.state = rng.state.wrapping_add(seed,);
+    rng.next_u32();
+    rng
+  }
+  /// Returns the next 32 random bits.
+  pub fn next_u32(&mut self,) -> u32 {
+    let state = self.state;
+
+    self.state = state.wrapping_mul(MULTIPLIER,).wrapping_add(self.increment,);
+
+    let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+
+    xorshifted.rotate_right((state >> 59) as u32,)
+  }
+  /// Returns the next 64 random bits.
+  #[inline]
+  pub fn next_u64(&mut self,) -> u64 { ((self.next_u32() as u64) << 32) | self.next_u32() as u64 }
+  /// Returns a number uniformly distributed in `[0, 1)`.
+  /// 
+  /// The number is built from 24 random bits, or as many as `Num` can represent. It is
+  /// built four bits at a time so `Num` must be able to represent `16`.
+  pub fn uniform<Num: Number + Clone,>(&mut self,) -> Num {
+    let sixteen = Num::from_isize(16,);
+    let mut bits = self.next_u32();
+    let mut uniform = Num::ZERO;
+
+    for _ in 0..6 {
+      uniform = (uniform + Num::from_isize((bits & 0xF) as isize,)) / sixteen.clone();
+      bits >>= 4;
+    }
+
+    uniform
+  }
+  /// Returns a number uniformly distributed in `[low, high)`.
+  /// 
+  /// # Params
+  /// 
+  /// low --- The inclusive lower bound.  
+  /// high --- The exclusive upper bound.  
+  pub fn range<Num: Number + Clone,>(&mut self, low: Num, high: Num,) -> Num {
+    low.clone() + ((high - low) * self.uniform())
+  }
+  /// Returns a Vector uniformly distributed in the cube `[-1, 1)^3`.
+  fn in_cube<Num: Number + Clone,>(&mut self,) -> Vector<Num,> {
+    let mut component = || self.range(Num::NEG_ONE, Num::ONE,);
+
+    Vector::new(component(), component(), component(),)
+  }
+  /// Returns a Vector uniformly distributed in the unit ball.
+  pub fn in_ball<Num: Number + Clone,>(&mut self,) -> Vector<Num,> {
+    //Rejection sampling avoids the cube root of the inverse transform.
+    loop {
+      let vector = self.in_cube();
+
+      if vector.clone().magnitude_squared() <= Num::ONE { return vector }
+    }
+  }
+  /// Returns a [Unit] uniformly distributed on the unit sphere.
+  pub fn on_sphere<Num: Sqrt + Clone,>(&mut self,) -> Unit<Num,> {
+    loop {
+      let vector = self.in_ball::<Num>();
+
+      //Vectors too short to normalise would bias the direction.
+      if vector.clone().magnitude_squared() > Num::ONE / Num::from_isize(64,) {
+        return vector.unit()
+      }
+    }
+  }
+  /// Returns a Vector uniformly distributed in the unit disc in the xy plane.
+  pub fn in_disc<Num: Number + Clone,>(&mut self,) -> Vector<Num,> {
+    loop {
+      let x = self.range(Num::NEG_ONE, Num::ONE,);
+      let y = self.range(Num::NEG_ONE, Num::ONE,);
+
+      if (x.clone() * x.clone()) + (y.clone() * y.clone()) <= Num::ONE {
+        return Vector::new(x, y, Num::ZERO,)
+      }
+    }
+  }
+  /// Returns a [Point] uniformly distributed in a triangle.
+  /// 
+  /// # Params
+  /// 
+  /// triangle --- The corners of the triangle.  
+  pub fn in_triangle<Num: Number + Clone,>(&mut self, triangle: [Point<Num,>; 3],) -> Point<Num,> {
+    let mut v = self.uniform::<Num>();
+    let mut w = self.uniform::<Num>();
+
+    //Reflect samples from the far half of the parallelogram back into the triangle.
+    if v.clone() + w.clone() > Num::ONE {
+      v = Num::ONE - v;
+      w = Num::ONE - w;
+    }
+
+    Point::barycentric(triangle, [Num::ONE - v.clone() - w.clone(), v, w,],)
+  }
+  /// Returns a uniformly distributed [Rotor] with a magnituid of 1.
+  pub fn rotor<Num: Sqrt + Clone,>(&mut self,) -> Rotor<Num,> {
+    //A Rotor is a unit quaternion, the direction of a point in the 4D unit ball is
+    //uniformly distributed over them.
+    loop {
+      let scalar = self.range(Num::NEG_ONE, Num::ONE,);
+      let plane = self.in_cube::<Num>();
+      let rotor = Rotor::new(scalar, Bivector::new(plane.x, plane.y, plane.z,),);
+      let magnitude_squared = rotor.clone().magnitude_squared();
+
+      //Rotors too short to normalise would bias the rotation.
+      if magnitude_squared <= Num::ONE && magnitude_squared > Num::ONE / Num::from_isize(64,) {
+        return rotor.normalize()
+      }
+    }
+  }
+  /// Returns a uniformly distributed [Rotation].
+  /// 
+  /// Converting the sampled [Rotor] into a [Rotation] uses [Trigonometry] for the angle.
+  #[inline]
+  pub fn rotation<Num: Sqrt + Trigonometry + Clone,>(&mut self,) -> Rotation<Num,> { self.rotor().rotation() }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_rng() {
+    //The reference output of the PCG32 demo program.
+    let mut rng = Rng::with_stream(42, 54,);
+    let expected = [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e,];
+    assert_eq!([0; 6].map(|_: u32,| rng.next_u32(),), expected, "next_u32 failed",);
+
+    let mut rng = Rng::new(7,);
+    assert_eq!(rng.clone().next_u64(), rng.clone().next_u64(), "Determinism failed",);
+    assert_ne!(Rng::new(7,).next_u32(), Rng::with_stream(7, 1,).next_u32(), "Streams failed",);
+
+    for _ in 0..1024 {
+      let uniform = rng.uniform::<f64>();
+      assert!((0.0..1.0).contains(&uniform,), "uniform failed",);
+
+      let range = rng.range(-3.0f32, 5.0,);
+      assert!((-3.0..5.0).contains(&range,), "range failed",);
+    }
+  }
+  #[test]
+  fn test_samplers() {
+    let mut rng = Rng::new(1,);
+    let triangle = [Point::new(0.0, 0.0, 1.0,), Point::new(2.0, 0.0, 1.0,), Point::new(0.0, 2.0, 1.0,),];
+
+    for _ in 0..256 {
+      assert!(rng.in_ball::<f64>().magnitude_squared() <= 1.0, "in_ball failed",);
+      assert_approx_eq!(rng.on_sphere::<f64>().vector().magnituid(), 1.0, "on_sphere failed",);
+
+      let disc = rng.in_disc::<f32>();
+      assert!(disc.z == 0.0 && disc.magnitude_squared() <= 1.0, "in_disc failed",);
+
+      let point = rng.in_triangle(triangle,);
+      assert!(point.z == 1.0 && point.x >= 0.0 && point.y >= 0.0 && point.x + point.y <= 2.0, "in_triangle failed",);
+
+      assert_abs_diff_eq!(rng.rotor::<f64>().magnitude_squared(), 1.0, 1e-9, "rotor failed",);
+
+      let rotation = rng.rotation::<f64>();
+      assert_approx_eq!(rotation.axis.vector().magnituid(), 1.0, "rotation axis failed",);
+      assert!((0.0..=std::f64::consts::TAU).contains(&rotation.angle.0,), "rotation angle failed",);
+    }
+
+    //The mean of a uniform distribution on the sphere is the origin.
+    let mean = (0..4096).map(|_,| rng.on_sphere::<f64>().vector(),)
+      .fold(Vector::ZERO, |sum, vector,| sum + vector,) / 4096.0;
+    assert!(mean.magnituid() < 0.05, "on_sphere is biased",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_samplers_fixed() {
+    use fixed_point::{Fixed32, consts::U16,};
+
+    let mut rng = Rng::new(3,);
+    let uniform = rng.clone().uniform::<Fixed32<U16>>();
+    assert_eq!(uniform, rng.uniform(), "Fixed32 determinism failed",);
+    assert!(uniform >= Fixed32::ZERO && uniform < Fixed32::ONE, "Fixed32 uniform failed",);
+
+    for _ in 0..256 {
+      assert!(rng.in_ball::<Fixed32<U16>>().magnitude_squared() <= Fixed32::ONE, "Fixed32 in_ball failed",);
+      assert!(rng.in_disc::<Fixed32<U16>>().magnitude_squared() <= Fixed32::ONE, "Fixed32 in_disc failed",);
+
+      let rotor = rng.clone().rotor::<Fixed32<U16>>();
+      assert_eq!(rotor, rng.rotor(), "Fixed32 rotor determinism failed",);
+      assert_abs_diff_eq!(rotor.magnitude_squared(), Fixed32::ONE, Fixed32::from(0.01,), "Fixed32 rotor failed",);
+    }
+  }
+}
@@ -0,0 +1,342 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::{ops, cmp::Ordering,};
+
+/// The real roots of a polynomial of at most degree four.
+/// 
+/// Roots are sorted in ascending order and exactly repeated roots are only reported once,
+/// rounding may still split a repeated root into close neighbours. If every coefficient is
+/// zero no roots are reported.
+#[derive(Clone, Copy, Debug,)]
+pub struct Roots<Num,> {
+  /// The storage for the roots.
+  roots: [Num; 4],
+  /// The number of roots found.
+  len: usize,
+}
+
+impl<Num: Number + Copy,> Roots<Num,> {
+  /// An empty set of roots.
+  #[inline]
+  fn empty() -> Self { Self { roots: [Num::ZERO; 4], len: 0, } }
+  /// Adds a root to the set.
+  #[inline]
+  fn push(&mut self, root: Num,) {
+    self.roots[self.len] = root;
+    self.len += 1;
+  }
+  /// Polishes, sorts and deduplicates the roots of a monic polynomial.
+  /// 
+  /// # Params
+  /// 
+  /// coefficients --- The coefficients of the polynomial, highest degree first.  
+  fn finish(mut self, coefficients: &[Num],) -> Self {
+    let roots = &mut self.roots[..self.len];
+
+    for root in roots.iter_mut() { *root = polish(*root, coefficients,) }
+    roots.sort_unstable_by(|lhs, rhs,| lhs.partial_cmp(rhs,).unwrap_or(Ordering::Equal,),);
+
+    let mut len = 0;
+
+    for index in 0..self.len {
+      if len == 0 || self.roots[len - 1] != self.roots[index] {
+        self.roots[len] = self.roots[index];
+        len += 1;
+      }
+    }
+
+    self.len = len;
+    self
+  }
+  /// Solves `ax + b = 0`.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The linear coefficient.  
+  /// b --- The constant coefficient.  
+  pub fn linear(a: Num, b: Num,) -> Self {
+    let mut roots = Self::empty();
+
+    if a != Num::ZERO { roots.push(-b / a,) }
+
+    roots
+  }
+}
+
+impl<Num: Sqrt + Copy,> Roots<Num,> {
+  /// Solves `ax^2 + bx + c = 0`.
+  /// 
+  /// Falls back to [Roots::linear] if `a` is zero.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The quadratic coefficient.  
+  /// b --- The linear coefficient.  
+  /// c --- The constant coefficient.  
+  pub fn quadratic(a: Num, b: Num, c: Num,) -> Self {
+    if a == Num::ZERO { return Self::linear(b, c,) }
+
+    let two = Num::from_isize(2,);
+    let discriminant = (b * b) - (Num::from_isize(4,) * a * c);
+    let mut roots = Self::empty();
+
+    if discriminant == Num::ZERO { roots.push(-b / (two * a),) }
+    else if discriminant > Num::ZERO {
+      //Avoid cancellation between `b` and the root of the discriminant.
+      let root = discriminant.sqrt();
+      let q = if b < Num::ZERO { (root - b) / two } else { -(b + root) / two };
+
+      roots.push(q / a,);
+      roots.push(c / q,);
+    }
+
+    roots.finish(&[Num::ONE, b / a, c / a,],)
+  }
+}
+
+impl<Num: Sqrt + Trigonometry + Copy,> Roots<Num,> {
+  /// Solves `ax^3 + bx^2 + cx + d = 0`.
+  /// 
+  /// Falls back to [Roots::quadratic] if `a` is zero.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The cubic coefficient.  
+  /// b --- The quadratic coefficient.  
+  /// c --- The linear coefficient.  
+  /// d --- The constant coefficient.  
+  pub fn cubic(a: Num, b: Num, c: Num, d: Num,) -> Self {
+    if a == Num::ZERO { return Self::quadratic(b, c, d,) }
+
+    let (b, c, d,) = (b / a, c / a, d / a,);
+
+    if d == Num::ZERO {
+      let mut roots = Self::quadratic(Num::ONE, b, c,);
+
+      roots.push(Num::ZERO,);
+      return roots.finish(&[Num::ONE, b, c, d,],)
+    }
+
+    //Substitute `x = t - b / 3` to get `t^3 + pt + q = 0`.
+    let (two, three,) = (Num::from_isize(2,), Num::from_isize(3,),);
+    let third = b / three;
+    let p = c - (b * third);
+    let q = (two * third * third * third) - (third * c) + d;
+    let (half_q, third_p,) = (q / two, p / three,);
+    let discriminant = (half_q * half_q) + (third_p * third_p * third_p);
+    let mut roots = Self::empty();
+
+    if discriminant > Num::ZERO {
+      //One real root, the larger cube root is taken first to avoid cancellation.
+      let root = discriminant.sqrt();
+      let u = cbrt(if half_q > Num::ZERO { -half_q - root } else { root - half_q },);
+
+      roots.push(u - (third_p / u) - third,);
+    } else if discriminant == Num::ZERO {
+      if p == Num::ZERO { roots.push(-third,) }
+      else {
+        roots.push((three * q / p) - third,);
+        roots.push((-three * q / (two * p)) - third,);
+      }
+    } else {
+      //Three real roots from the trigonometric form.
+      let root = (-third_p).sqrt();
+      let cosine = -half_q / (-third_p * root);
+      let cosine = if cosine > Num::ONE { Num::ONE } else if cosine < Num::NEG_ONE { Num::NEG_ONE } else { cosine };
+      let angle = cosine.acos() / three;
+      let step = Num::TAU / three;
+
+      for k in 0..3 {
+        roots.push((two * root * (angle - (step * Num::from_isize(k,))).cos()) - third,);
+      }
+    }
+
+    roots.finish(&[Num::ONE, b, c, d,],)
+  }
+  /// Solves `ax^4 + bx^3 + cx^2 + dx + e = 0`.
+  /// 
+  /// Falls back to [Roots::cubic] if `a` is zero.
+  /// 
+  /// # Params
+  /// 
+  /// a --- The quartic coefficient.  
+  /// b --- The cubic coefficient.  
+  /// c --- The quadratic coefficient.  
+  /// d --- The linear coefficient.  
+  /// e --- The constant coefficient.  
+  pub fn quartic(a: Num, b: Num, c: Num, d: Num, e: Num,) -> Self {
+    if a == Num::ZERO { return Self::cubic(b, c, d, e,) }
+
+    let (b, c, d, e,) = (b / a, c / a, d / a, e / a,);
+    let coefficients = [Num::ONE, b, c, d, e,];
+
+    if e == Num::ZERO {
+      let mut roots = Self::cubic(Num::ONE, b, c, d,);
+
+      roots.push(Num::ZERO,);
+      return roots.finish(&coefficients,)
+    }
+
+    //Substitute `x = y - b / 4` to get `y^4 + py^2 + qy + r = 0`.
+    let two = Num::from_isize(2,);
+    let quarter = b / Num::from_isize(4,);
+    let square = quarter * quarter;
+    let p = c - (Num::from_isize(6,) * square);
+    let q = d - (two * quarter * c) + (Num::from_isize(8,) * square * quarter);
+    let r = e - (quarter * d) + (square * c) - (Num::from_isize(3,) * square * square);
+    let mut roots = Self::empty();
+    let resolvent = if q == Num::ZERO { Self::empty() }
+      else { Self::cubic(Num::ONE, p, (p * p / Num::from_isize(4,)) - r, -(q * q) / Num::from_isize(8,),) };
+
+    match resolvent.last() {
+      //Ferrari's method, any positive root of the resolvent cubic factors the quartic.
+      Some(&m) if m > Num::ZERO => {
+        let s = (two * m).sqrt();
+        let (half, offset,) = ((p / two) + m, q / (two * s),);
+
+        for factor in [Self::quadratic(Num::ONE, s, half - offset,), Self::quadratic(Num::ONE, -s, half + offset,),].iter() {
+          for &y in factor.iter() { roots.push(y - quarter,) }
+        }
+      },
+      //Biquadratic, solve for `y^2`. The largest resolvent root is only zero when `q` is
+      //zero, when `q` is tiny it rounds to zero and `q` is treated as zero.
+      _ => for &z in Self::quadratic(Num::ONE, p, r,).iter().filter(|&&z,| z >= Num::ZERO,) {
+        let y = z.sqrt();
+
+        roots.push(y - quarter,);
+        roots.push(-y - quarter,);
+      },
+    }
+
+    roots.finish(&coefficients,)
+  }
+}
+
+impl<Num,> ops::Deref for Roots<Num,> {
+  type Target = [Num];
+
+  #[inline]
+  fn deref(&self,) -> &Self::Target { &self.roots[..self.len] }
+}
+
+impl<Num: PartialEq,> PartialEq for Roots<Num,> {
+  #[inline]
+  fn eq(&self, rhs: &Self,) -> bool { **self == **rhs }
+}
+
+impl<'a, Num,> IntoIterator for &'a Roots<Num,> {
+  type Item = &'a Num;
+  type IntoIter = core::slice::Iter<'a, Num,>;
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter() }
+}
+
+/// Refines a root of a monic polynomial with Newton's method.
+/// 
+/// Steps are only taken while they reduce the residual.
+fn polish<Num: Number + Copy,>(mut root: Num, coefficients: &[Num],) -> Num {
+  let evaluate = |x: Num,| {
+    coefficients.iter().fold((Num::ZERO, Num::ZERO,), |(value, derivative,), &coefficient,| {
+      ((value * x) + coefficient, (derivative * x) + value,)
+    },)
+  };
+  let (mut value, mut derivative,) = evaluate(root,);
+
+  for _ in 0..8 {
+    if value == Num::ZERO || derivative == Num::ZERO { break }
+
+    let next = root - (value / derivative);
+    let (next_value, next_derivative,) = evaluate(next,);
+
+    if abs(next_value,) >= abs(value,) { break }
+
+    root = next;
+    value = next_value;
+    derivative = next_derivative;
+  }
+
+  root
+}
+
+/// The real cube root of a number.
+fn cbrt<Num: Sqrt + Copy,>(num: Num,) -> Num {
+  if num < Num::ZERO { return -cbrt(-num,) }
+  if num == Num::ZERO { return num }
+
+  //After the first step Newton's method decreases monotonically onto the root.
+  let three = Num::from_isize(3,);
+  let mut root = num.sqrt();
+
+  root = ((root + root) + (num / (root * root))) / three;
+  for _ in 0..128 {
+    let next = ((root + root) + (num / (root * root))) / three;
+
+    if next >= root { break }
+
+    root = next;
+  }
+
+  root
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  fn check(roots: Roots<f64,>, expected: &[f64], message: &str,) {
+    assert_eq!(roots.len(), expected.len(), "{}", message,);
+    for (&root, &expected,) in roots.iter().zip(expected,) {
+      assert_abs_diff_eq!(root, expected, 1e-9 * expected.abs().max(1.0,), "{}", message,);
+    }
+  }
+
+  #[test]
+  fn test_quadratic() {
+    check(Roots::quadratic(1.0, -4.0, 3.0,), &[1.0, 3.0,], "Quadratic failed 1",);
+    check(Roots::quadratic(-2.0, 0.0, 8.0,), &[-2.0, 2.0,], "Quadratic failed 2",);
+    check(Roots::quadratic(1.0, -2.0, 1.0,), &[1.0,], "Quadratic double root failed",);
+    check(Roots::quadratic(1.0, 0.0, 1.0,), &[], "Quadratic complex roots failed",);
+    check(Roots::quadratic(0.0, 2.0, -4.0,), &[2.0,], "Quadratic degenerate failed",);
+    check(Roots::quadratic(0.0, 0.0, 0.0,), &[], "Quadratic zero failed",);
+    //Large `b` loses the small root to cancellation in the textbook formula.
+    check(Roots::quadratic(1.0, -1e8, 1.0,), &[1e-8, 1e8,], "Quadratic cancellation failed",);
+  }
+  #[test]
+  fn test_cubic() {
+    check(Roots::cubic(1.0, -6.0, 11.0, -6.0,), &[1.0, 2.0, 3.0,], "Cubic failed 1",);
+    check(Roots::cubic(2.0, -4.0, 2.0, -4.0,), &[2.0,], "Cubic failed 2",);
+    check(Roots::cubic(1.0, -3.0, 3.0, -1.0,), &[1.0,], "Cubic triple root failed",);
+    check(Roots::cubic(1.0, 0.0, -1.0, 0.0,), &[-1.0, 0.0, 1.0,], "Cubic zero root failed",);
+    check(Roots::cubic(1.0, 0.0, -3.0, -2.0,), &[-1.0, 2.0,], "Cubic double root failed",);
+    check(Roots::cubic(0.0, 1.0, -4.0, 3.0,), &[1.0, 3.0,], "Cubic degenerate failed",);
+  }
+  #[test]
+  fn test_quartic() {
+    check(Roots::quartic(1.0, -10.0, 35.0, -50.0, 24.0,), &[1.0, 2.0, 3.0, 4.0,], "Quartic failed 1",);
+    check(Roots::quartic(1.0, 1.0, -1.0, 1.0, -2.0,), &[-2.0, 1.0,], "Quartic failed 2",);
+    check(Roots::quartic(1.0, 0.0, -5.0, 0.0, 4.0,), &[-2.0, -1.0, 1.0, 2.0,], "Quartic biquadratic failed",);
+    check(Roots::quartic(1.0, 0.0, 0.0, 0.0, 1.0,), &[], "Quartic complex roots failed",);
+
+    let root = ((5.0f64.sqrt() - 1.0) / 2.0).sqrt();
+    check(Roots::quartic(1.0, 0.0, 1.0, 1e-170, -1.0,), &[-root, root,], "Quartic tiny q failed",);
+    check(Roots::quartic(3.0, -6.0, -3.0, 6.0, 0.0,), &[-1.0, 0.0, 1.0, 2.0,], "Quartic zero root failed",);
+    check(Roots::quartic(0.0, 1.0, -6.0, 11.0, -6.0,), &[1.0, 2.0, 3.0,], "Quartic degenerate failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_roots_fixed() {
+    use fixed_point::{Fixed32, consts::U16,};
+
+    let roots = Roots::<Fixed32<U16>>::cubic(1.into(), (-6).into(), 11.into(), (-6).into(),);
+    assert_eq!(roots.len(), 3, "Fixed32 cubic failed 1",);
+    for (&root, expected,) in roots.iter().zip(1..,) {
+      assert_abs_diff_eq!(root, Fixed32::from(expected,), Fixed32::from(0.001,), "Fixed32 cubic failed 2",);
+    }
+
+    let roots = Roots::<Fixed32<U16>>::quadratic(1.into(), (-4).into(), 3.into(),);
+    assert_eq!(&*roots, &[1.into(), 3.into(),], "Fixed32 quadratic failed",);
+  }
+}
@@ -1,21 +1,32 @@
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-21
+//! Last Moddified --- 2026-10-19
 
 use crate::*;
+use core::{fmt, str::FromStr,};
 
 /// A rotation in 3D space.
 #[derive(Clone, Copy, Debug,)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,),)]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "Num: Number + Clone + serde::Deserialize<'de>",),),)]
 pub struct Rotation<Num,> {
   /// The axis around which the rotation occours.
   pub axis: Unit<Num,>,
   /// The angle of the rotation.
-  pub angle: Num,
+  pub angle: Radians<Num,>,
 }
 
 impl<Num,> Rotation<Num,> {
   /// Creates a new [Rotation] value.
+  /// 
+  /// # Params
+  /// 
+  /// axis --- The axis around which the rotation occours.  
+  /// angle --- The angle of the rotation as [Radians] or [Degrees].  
   #[inline]
-  pub const fn new(axis: Unit<Num,>, angle: Num,) -> Self { Self { axis, angle, } }
+  pub fn new<Angle,>(axis: Unit<Num,>, angle: Angle,) -> Self
+    where Angle: Into<Radians<Num,>>, {
+    Self { axis, angle: angle.into(), }
+  }
 }
 
 impl<Num,> Rotation<Num,>
@@ -35,13 +46,39 @@ impl<Num,> Rotation<Num,>
     let dot2 = dot.clone() * dot;
     let from_mag2 = from.clone() * from.clone();
     let to_mag2 = to.clone() * to.clone();
-    let angle = (dot2 / (from_mag2 * to_mag2)).acos();
-    let axis = Vector::cross(from, to,).into();
+    let angle = Radians((dot2 / (from_mag2 * to_mag2)).acos(),);
+    //Parallel Vectors have no rotation between them so any axis will do.
+    let axis = Vector::cross(from, to,).normalize_or(Unit::X,);
 
     Self { axis, angle, }
   }
 }
 
+/// Formats as the axis followed by the angle in radians, such as `(0, 0, 1) 1.5`.
+impl<Num: fmt::Display,> fmt::Display for Rotation<Num,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt::Display::fmt(&self.axis, fmt,)?;
+    fmt.write_str(" ",)?;
+    fmt::Display::fmt(&self.angle.0, fmt,)
+  }
+}
+
+/// Parses the axis in either [Vector] format followed by the angle in radians.
+impl<Num: FromStr + Number + Clone,> FromStr for Rotation<Num,> {
+  type Err = ParseVectorError<Num::Err,>;
+
+  fn from_str(from: &str,) -> Result<Self, Self::Err> {
+    let from = from.trim();
+
+    if from.ends_with(')',) { return Err(ParseVectorError::MissingAngle) }
+
+    let (axis, angle,) = from.rsplit_once(char::is_whitespace,).ok_or(ParseVectorError::MissingAngle,)?;
+    let angle = angle.parse().map_err(ParseVectorError::Angle,)?;
+
+    Ok(Rotation::new(axis.parse()?, Radians(angle,),))
+  }
+}
+
 #[cfg(test,)]
 mod tests {
   use super::*;
@@ -55,15 +92,33 @@ mod tests {
     const PI2: f32 = std::f32::consts::FRAC_PI_2;
 
     let rot = Rotation::between(x, y,);
-    assert_eq!(rot.axis, z, "Rotation axis is wrong",);
-    assert_eq!(rot.angle, PI2, "Rotation angle is wrong",);
+    assert_approx_eq!(rot.axis, z, "Rotation axis is wrong",);
+    assert_approx_eq!(rot.angle, Radians(PI2,), "Rotation angle is wrong",);
 
     let rot = Rotation::between(y, z,);
-    assert_eq!(rot.axis, x, "Rotation axis is wrong",);
-    assert_eq!(rot.angle, PI2, "Rotation angle is wrong",);
+    assert_approx_eq!(rot.axis, x, "Rotation axis is wrong",);
+    assert_approx_eq!(rot.angle, Radians(PI2,), "Rotation angle is wrong",);
 
     let rot = Rotation::between(x, z,);
-    assert_eq!(rot.axis, y, "Rotation axis is wrong",);
-    assert_eq!(rot.angle, PI2, "Rotation angle is wrong",);
+    assert_approx_eq!(rot.axis, -y, "Rotation axis is wrong",);
+    assert_approx_eq!(rot.angle, Radians(PI2,), "Rotation angle is wrong",);
+
+    let rot = Rotation::new(x.unit(), Degrees(90.0,),);
+    assert_approx_eq!(rot.angle, Radians(PI2,), "Rotation from degrees is wrong",);
+  }
+  #[test]
+  fn test_rotation_format() {
+    let rotation = Rotation::new(Unit::Z, Radians(0.5,),);
+    assert_eq!(rotation.to_string(), "(0, 0, 1) 0.5", "Display failed",);
+    assert_eq!(format!("{:.1}", rotation,), "(0.0, 0.0, 1.0) 0.5", "Display precision failed",);
+
+    let parsed = "(0, 0, 1) 0.5".parse::<Rotation<f64>>().expect("Parse failed 1");
+    assert_eq!((parsed.axis, parsed.angle,), (rotation.axis, rotation.angle,), "Parse failed 1",);
+    let parsed = " 0 0 1 0.5 ".parse::<Rotation<f64>>().expect("Parse failed 2");
+    assert_eq!((parsed.axis, parsed.angle,), (rotation.axis, rotation.angle,), "Parse failed 2",);
+
+    assert!(matches!("(0, 0, 1)".parse::<Rotation<f64>>(), Err(ParseVectorError::MissingAngle),), "Missing angle accepted",);
+    assert!(matches!("(0, 0, 1) x".parse::<Rotation<f64>>(), Err(ParseVectorError::Angle(_,)),), "Invalid angle accepted",);
+    assert!(matches!("(0, 2, 0) 1".parse::<Rotation<f64>>(), Err(ParseVectorError::NotUnit),), "Non unit axis accepted",);
   }
 }
@@ -0,0 +1,206 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::ops;
+
+/// A rotation in 3D space as the sum of a scalar and a [Bivector].
+/// 
+/// A Rotor rotates within the plane of its [Bivector] rather than around an axis, the
+/// same construction works in 2D and 4D where an axis does not exist. Building a Rotor
+/// from two Vectors with [Rotor::between] never needs to choose an axis, only opposite
+/// Vectors have no unique plane.
+/// 
+/// Most operations assume the Rotor has a magnituid of 1, see [Rotor::normalize].
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,),)]
+pub struct Rotor<Num,> {
+  /// The cosine of half the angle of rotation.
+  pub scalar: Num,
+  /// The plane of rotation scaled by the negated sine of half the angle of rotation.
+  pub bivector: Bivector<Num,>,
+}
+
+impl<Num,> Rotor<Num,> {
+  /// Builds a new Rotor value.
+  /// 
+  /// # Params
+  /// 
+  /// scalar --- The scalar part.  
+  /// bivector --- The bivector part.  
+  #[inline]
+  pub const fn new(scalar: Num, bivector: Bivector<Num,>,) -> Self { Self { scalar, bivector, } }
+}
+
+impl<Num: Number,> Rotor<Num,> {
+  /// The rotation which leaves every Vector unchanged.
+  pub const IDENTITY: Self = Self::new(Num::ONE, Bivector::ZERO,);
+}
+
+impl<Num: Number + Clone,> Rotor<Num,> {
+  /// Returns the reverse of this Rotor.
+  /// 
+  /// For a normalised Rotor this is the inverse rotation.
+  #[inline]
+  pub fn reverse(self,) -> Self { Self::new(self.scalar, -self.bivector,) }
+  /// Returns the square of the magnituid of this Rotor.
+  #[inline]
+  pub fn magnitude_squared(self,) -> Num {
+    (self.scalar.clone() * self.scalar) + self.bivector.magnitude_squared()
+  }
+  /// Rotates `vector`.
+  /// 
+  /// # Params
+  /// 
+  /// vector --- The Vector to rotate.  
+  pub fn rotate(&self, vector: Vector<Num,>,) -> Vector<Num,> {
+    //The Rotor is the unit quaternion `scalar - normal`.
+    let axis = -self.bivector.clone().normal();
+    let twice = Vector::cross(axis.clone(), vector.clone(),) * Num::from_isize(2,);
+
+    vector + (twice.clone() * self.scalar.clone()) + Vector::cross(axis, twice,)
+  }
+}
+
+impl<Num,> Rotor<Num,>
+  where Num: Sqrt + Trigonometry + Clone, {
+  /// Builds the Rotor which rotates by `angle` within `plane`.
+  /// 
+  /// A positive angle rotates from the first Vector of `plane` towards the second.
+  /// 
+  /// # Params
+  /// 
+  /// plane --- The plane of rotation, which may not have a magnituid of zero.  
+  /// angle --- The angle of the rotation as [Radians] or [Degrees].  
+  pub fn from_plane<Angle,>(plane: Bivector<Num,>, angle: Angle,) -> Self
+    where Angle: Into<Radians<Num,>>, {
+    let (sin, cos,) = (angle.into() / Num::from_isize(2,)).sin_cos();
+    let plane = plane.clone() / plane.magnituid();
+
+    Self::new(cos, plane * -sin,)
+  }
+  /// Builds the Rotor equivalent to `rotation`.
+  /// 
+  /// # Params
+  /// 
+  /// rotation --- The rotation to convert.  
+  #[inline]
+  pub fn from_rotation(rotation: &Rotation<Num,>,) -> Self {
+    Self::from_plane(Bivector::from_normal(rotation.axis.clone().vector(),), rotation.angle.clone(),)
+  }
+  /// Returns the [Rotation] equivalent to this Rotor.
+  pub fn rotation(&self,) -> Rotation<Num,> {
+    let sin = self.bivector.clone().magnituid();
+    let angle = sin.atan2(self.scalar.clone(),) * Num::from_isize(2,);
+
+    Rotation::new((-self.bivector.clone().normal()).normalize_or(Unit::X,), Radians(angle,),)
+  }
+  /// Finds the Rotor to go from `from` to `to`.
+  /// 
+  /// Differences in the length of the [Vector]s have no affect on the Rotor returned.
+  /// Neither Vector may have a length of zero.
+  /// 
+  /// # Params
+  /// 
+  /// from --- The [Vector] to find the Rotor from.  
+  /// to --- The [Vector] to rotate `from` into.  
+  pub fn between(from: Vector<Num,>, to: Vector<Num,>,) -> Self {
+    //The geometric product `to * from` is a Rotor of twice the angle between the Vectors,
+    //adding the product of their lengths to the scalar halves the angle.
+    let lengths = (from.clone().magnitude_squared() * to.clone().magnitude_squared()).sqrt();
+    let dot = Vector::dot(from.clone(), to.clone(),);
+    let plane = Bivector::wedge(to, from.clone(),);
+    //Near opposite Vectors the rounding error in the scalar swamps its true value, once the
+    //plane is this small choosing any plane is at least as accurate.
+    let opposite = dot < Num::ZERO
+      && plane.clone().magnitude_squared() <= Num::EPSILON * lengths.clone() * lengths.clone();
+
+    if !opposite { return Self::new(lengths + dot, plane,).normalize() }
+
+    //Opposite Vectors can be rotated in any plane containing them.
+    let x = Bivector::wedge(from.clone(), Vector::X,);
+    let y = Bivector::wedge(from, Vector::Y,);
+    let plane = if x.clone().magnitude_squared() > y.clone().magnitude_squared() { x } else { y };
+
+    Self::new(Num::ZERO, plane.clone() / plane.magnituid(),)
+  }
+}
+
+impl<Num: Sqrt + Clone,> Rotor<Num,> {
+  /// Returns this Rotor scaled to a magnituid of 1.
+  /// 
+  /// Normalising removes drift accumulated over many compositions.
+  pub fn normalize(self,) -> Self {
+    let length = self.clone().magnitude_squared().sqrt();
+
+    Self::new(self.scalar / length.clone(), self.bivector / length,)
+  }
+}
+
+/// Composes two Rotors, `lhs * rhs` rotates by `rhs` and then by `lhs`.
+impl<Num: Number + Clone,> ops::Mul for Rotor<Num,> {
+  type Output = Self;
+
+  fn mul(self, rhs: Self,) -> Self::Output {
+    /*
+    (s1 + B1)(s2 + B2) = (s1s2 - b1.b2) + (s1B2 + s2B1 - B(b1 x b2))
+    where b is the normal of B.
+    */
+    let (s1, b1,) = (self.scalar, self.bivector.normal(),);
+    let (s2, b2,) = (rhs.scalar, rhs.bivector.normal(),);
+    let scalar = (s1.clone() * s2.clone()) - Vector::dot(b1.clone(), b2.clone(),);
+    let normal = (b2.clone() * s1) + (b1.clone() * s2) - Vector::cross(b1, b2,);
+
+    Self::new(scalar, Bivector::from_normal(normal,),)
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_rotor() {
+    const PI2: f64 = std::f64::consts::FRAC_PI_2;
+
+    let rotor = Rotor::from_plane(Bivector::XY, Radians(PI2,),);
+    assert_abs_diff_eq!(rotor.rotate(Vector::X,), Vector::Y, 1e-12, "Rotate failed 1",);
+    assert_abs_diff_eq!(rotor.rotate(Vector::Z,), Vector::Z, 1e-12, "Rotate failed 2",);
+    assert_abs_diff_eq!(rotor.reverse().rotate(Vector::Y,), Vector::X, 1e-12, "Reverse failed",);
+    assert_abs_diff_eq!(Rotor::IDENTITY.rotate(Vector::new(1.0, 2.0, 3.0,),), Vector::new(1.0, 2.0, 3.0,), 1e-12, "Identity failed",);
+
+    let rotation = Rotation::new(Vector::new(1.0, -2.0, 0.5,).unit(), Radians(2.0,),);
+    let rotor = Rotor::from_rotation(&rotation,);
+    let vector = Vector::new(-3.0, 0.25, 4.0,);
+    assert_abs_diff_eq!(rotor.rotate(vector,), vector.rotate(&rotation,), 1e-12, "from_rotation failed",);
+    assert_abs_diff_eq!(rotor.rotation(), rotation, 1e-12, "rotation failed",);
+
+    let (from, to,) = (Vector::new(1.0, 2.0, -2.0,), Vector::new(0.0, -4.0, 3.0,),);
+    let rotor = Rotor::between(from, to,);
+    assert_abs_diff_eq!(rotor.magnitude_squared(), 1.0, 1e-12, "between is not normalised",);
+    assert_abs_diff_eq!(rotor.rotate(from,), to * (3.0 / 5.0), 1e-12, "between failed",);
+    assert_abs_diff_eq!(Rotor::between(from, from * 2.0,), Rotor::IDENTITY, 1e-12, "Parallel between failed",);
+    assert_abs_diff_eq!(Rotor::between(from, -from,).rotate(from,), -from, 1e-12, "Opposite between failed 1",);
+    assert_abs_diff_eq!(Rotor::<f64>::between(Vector::X, Vector::NEG_X,).rotate(Vector::X,), Vector::NEG_X, 1e-12, "Opposite between failed 2",);
+    for &scale in [-0.1, -0.3, -0.7, -3.0,].iter() {
+      assert_abs_diff_eq!(Rotor::between(from, from * scale,).rotate(from,), -from, 1e-12, "Scaled opposite between failed",);
+    }
+
+    let first = Rotor::from_plane(Bivector::YZ, Degrees(30.0,),);
+    let second = Rotor::from_plane(Bivector::wedge(from, to,), Radians(1.25,),);
+    assert_abs_diff_eq!((second * first).rotate(vector,), second.rotate(first.rotate(vector,),), 1e-12, "Compose failed",);
+    assert_abs_diff_eq!(first * first.reverse(), Rotor::IDENTITY, 1e-12, "Compose inverse failed",);
+    assert_abs_diff_eq!((first * first).normalize(), first * first, 1e-12, "normalize failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_rotor_fixed() {
+    use fixed_point::{Fixed64, consts::U32,};
+
+    let from = Vector::<Fixed64<U32>>::new(1.into(), 0.into(), 0.into(),);
+    let to = Vector::new(0.into(), 0.into(), 2.into(),);
+    let rotor = Rotor::between(from, to,);
+    assert_abs_diff_eq!(rotor.rotate(from,), to / Fixed64::from(2,), Fixed64::from(1e-6,), "Fixed64 between failed",);
+    assert_abs_diff_eq!(rotor.rotation(), Rotation::new(Unit::NEG_Y, Radians(Fixed64::PI / 2,),), Fixed64::from(1e-6,), "Fixed64 rotation failed",);
+  }
+}
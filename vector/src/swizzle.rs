@@ -0,0 +1,87 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+
+/// Generates swizzle accessors for [Vector] returning two components.
+macro_rules! swizzle2 {
+  ($($name:ident => $a:ident $b:ident,)*) => {$(
+    #[doc = concat!("Returns the `", stringify!($name), "` components of this Vector.",)]
+    #[inline]
+    pub fn $name(&self,) -> [Num; 2] { [self.$a.clone(), self.$b.clone(),] }
+  )*};
+}
+
+/// Generates swizzle accessors for [Vector] returning three components.
+macro_rules! swizzle3 {
+  ($($name:ident => $a:ident $b:ident $c:ident,)*) => {$(
+    #[doc = concat!("Returns the `", stringify!($name), "` components of this Vector.",)]
+    #[inline]
+    pub fn $name(&self,) -> Vector<Num,> {
+      Vector::new(self.$a.clone(), self.$b.clone(), self.$c.clone(),)
+    }
+  )*};
+}
+
+/// Swizzle accessors in the style of shading languages.
+/// 
+/// Two component swizzles return an array as there is no 2D Vector type.
+impl<Num: Clone,> Vector<Num,> {
+  swizzle2! {
+    xx => x x,
+    xy => x y,
+    xz => x z,
+    yx => y x,
+    yy => y y,
+    yz => y z,
+    zx => z x,
+    zy => z y,
+    zz => z z,
+  }
+  swizzle3! {
+    xxx => x x x,
+    xxy => x x y,
+    xxz => x x z,
+    xyx => x y x,
+    xyy => x y y,
+    xyz => x y z,
+    xzx => x z x,
+    xzy => x z y,
+    xzz => x z z,
+    yxx => y x x,
+    yxy => y x y,
+    yxz => y x z,
+    yyx => y y x,
+    yyy => y y y,
+    yyz => y y z,
+    yzx => y z x,
+    yzy => y z y,
+    yzz => y z z,
+    zxx => z x x,
+    zxy => z x y,
+    zxz => z x z,
+    zyx => z y x,
+    zyy => z y y,
+    zyz => z y z,
+    zzx => z z x,
+    zzy => z z y,
+    zzz => z z z,
+  }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_swizzle() {
+    let vec = Vector::new(1, 2, 3,);
+
+    assert_eq!(vec.xy(), [1, 2,], "xy failed",);
+    assert_eq!(vec.zx(), [3, 1,], "zx failed",);
+    assert_eq!(vec.yy(), [2, 2,], "yy failed",);
+    assert_eq!(vec.xyz(), vec, "xyz failed",);
+    assert_eq!(vec.zyx(), Vector::new(3, 2, 1,), "zyx failed",);
+    assert_eq!(vec.xxz(), Vector::new(1, 1, 3,), "xxz failed",);
+  }
+}
@@ -0,0 +1,195 @@
+//! Author --- daniel.bechaz@gmail.com  
+//! Last Moddified --- 2026-10-19
+
+use crate::*;
+use core::{
+  ops, fmt,
+  hash::{Hash, Hasher,},
+  cmp::Ordering,
+  marker::PhantomData,
+};
+
+/// A value tagged with the coordinate space it is expressed in.
+/// 
+/// Values in different spaces cannot be combined with the arithmetic operators, they
+/// must first be moved into the same space using [Tagged::transform].
+/// 
+/// `Tagged` has the same memory layout as the value it wraps.
+#[repr(transparent,)]
+pub struct Tagged<T, Space,>(T, PhantomData<fn() -> Space>,);
+
+/// The world coordinate space.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum World {}
+
+/// The local coordinate space of an object.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum Local {}
+
+/// The coordinate space of a viewer.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum View {}
+
+impl<T, Space,> Tagged<T, Space,> {
+  /// Tags `value` as being expressed in `Space`.
+  #[inline]
+  pub const fn new(value: T,) -> Self { Tagged(value, PhantomData,) }
+  /// Removes the tag from this value.
+  #[inline]
+  pub fn into_inner(self,) -> T { self.0 }
+  /// Applies a function to the value without changing its space.
+  /// 
+  /// # Params
+  /// 
+  /// f --- The function to apply.  
+  #[inline]
+  pub fn map<F,>(self, f: F,) -> Self
+    where F: FnOnce(T,) -> T, { Tagged::new(f(self.0,),) }
+  /// Transforms the value into another space.
+  /// 
+  /// # Params
+  /// 
+  /// f --- The transformation from `Space` into `To`.  
+  #[inline]
+  pub fn transform<To, F,>(self, f: F,) -> Tagged<T, To,>
+    where F: FnOnce(T,) -> T, { Tagged::new(f(self.0,),) }
+  /// Reinterprets the value as being expressed in another space without changing it.
+  #[inline]
+  pub fn retag<To,>(self,) -> Tagged<T, To,> { Tagged::new(self.0,) }
+}
+
+impl<T, Space,> ops::Deref for Tagged<T, Space,> {
+  type Target = T;
+
+  #[inline]
+  fn deref(&self,) -> &Self::Target { &self.0 }
+}
+
+impl<T: Clone, Space,> Clone for Tagged<T, Space,> {
+  #[inline]
+  fn clone(&self,) -> Self { Tagged::new(self.0.clone(),) }
+}
+
+impl<T: Copy, Space,> Copy for Tagged<T, Space,> {}
+
+impl<T: PartialEq, Space,> PartialEq for Tagged<T, Space,> {
+  #[inline]
+  fn eq(&self, rhs: &Self,) -> bool { self.0 == rhs.0 }
+}
+
+impl<T: Eq, Space,> Eq for Tagged<T, Space,> {}
+
+impl<T: PartialOrd, Space,> PartialOrd for Tagged<T, Space,> {
+  #[inline]
+  fn partial_cmp(&self, rhs: &Self,) -> Option<Ordering> { self.0.partial_cmp(&rhs.0,) }
+}
+
+impl<T: Hash, Space,> Hash for Tagged<T, Space,> {
+  #[inline]
+  fn hash<H: Hasher,>(&self, state: &mut H,) { self.0.hash(state,) }
+}
+
+impl<T: fmt::Debug, Space,> fmt::Debug for Tagged<T, Space,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.debug_tuple("Tagged",)
+    .field(&self.0,)
+    .field(&core::any::type_name::<Space>(),)
+    .finish()
+  }
+}
+
+impl<T: Default, Space,> Default for Tagged<T, Space,> {
+  #[inline]
+  fn default() -> Self { Tagged::new(T::default(),) }
+}
+
+impl<T: ops::Neg<Output = T>, Space,> ops::Neg for Tagged<T, Space,> {
+  type Output = Self;
+
+  #[inline]
+  fn neg(self,) -> Self::Output { Tagged::new(-self.0,) }
+}
+
+impl<T: ops::Add<Output = T>, Space,> ops::Add for Tagged<T, Space,> {
+  type Output = Self;
+
+  #[inline]
+  fn add(self, rhs: Self,) -> Self::Output { Tagged::new(self.0 + rhs.0,) }
+}
+
+impl<T: ops::AddAssign, Space,> ops::AddAssign for Tagged<T, Space,> {
+  #[inline]
+  fn add_assign(&mut self, rhs: Self,) { self.0 += rhs.0 }
+}
+
+impl<T: ops::Sub<Output = T>, Space,> ops::Sub for Tagged<T, Space,> {
+  type Output = Self;
+
+  #[inline]
+  fn sub(self, rhs: Self,) -> Self::Output { Tagged::new(self.0 - rhs.0,) }
+}
+
+impl<T: ops::SubAssign, Space,> ops::SubAssign for Tagged<T, Space,> {
+  #[inline]
+  fn sub_assign(&mut self, rhs: Self,) { self.0 -= rhs.0 }
+}
+
+/// Multiplying two values in the same space, such as the dot product of two
+/// [Vector]s, produces an untagged result.
+impl<T: ops::Mul, Space,> ops::Mul for Tagged<T, Space,> {
+  type Output = T::Output;
+
+  #[inline]
+  fn mul(self, rhs: Self,) -> Self::Output { self.0 * rhs.0 }
+}
+
+impl<Num, Space,> ops::Mul<Num> for Tagged<Vector<Num,>, Space,>
+  where Vector<Num,>: ops::Mul<Num, Output = Vector<Num,>>, {
+  type Output = Self;
+
+  #[inline]
+  fn mul(self, rhs: Num,) -> Self::Output { Tagged::new(self.0 * rhs,) }
+}
+
+impl<Num, Space,> ops::MulAssign<Num> for Tagged<Vector<Num,>, Space,>
+  where Vector<Num,>: ops::MulAssign<Num>, {
+  #[inline]
+  fn mul_assign(&mut self, rhs: Num,) { self.0 *= rhs }
+}
+
+impl<Num, Space,> ops::Div<Num> for Tagged<Vector<Num,>, Space,>
+  where Vector<Num,>: ops::Div<Num, Output = Vector<Num,>>, {
+  type Output = Self;
+
+  #[inline]
+  fn div(self, rhs: Num,) -> Self::Output { Tagged::new(self.0 / rhs,) }
+}
+
+impl<Num, Space,> ops::DivAssign<Num> for Tagged<Vector<Num,>, Space,>
+  where Vector<Num,>: ops::DivAssign<Num>, {
+  #[inline]
+  fn div_assign(&mut self, rhs: Num,) { self.0 /= rhs }
+}
+
+#[cfg(test,)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_tagged() {
+    let world = Tagged::<_, World,>::new(Vector::new(1, 2, 3,),);
+    let other = Tagged::<_, World,>::new(Vector::new(3, 2, 1,),);
+
+    assert_eq!(std::mem::size_of_val(&world,), std::mem::size_of::<Vector<i32,>>(), "Tagged is not zero cost",);
+    assert_eq!((world + other).into_inner(), Vector::new(4, 4, 4,), "Addition failed",);
+    assert_eq!((world - other).into_inner(), Vector::new(-2, 0, 2,), "Subtraction failed",);
+    assert_eq!((-world * 2).into_inner(), Vector::new(-2, -4, -6,), "Scaling failed",);
+    assert_eq!(world * other, 10, "Dot product failed",);
+    assert_eq!(world.x, 1, "Deref failed",);
+
+    let local: Tagged<_, Local,> = world.transform(|vec,| vec - Vector::new(1, 1, 1,),);
+    assert_eq!(local.into_inner(), Vector::new(0, 1, 2,), "transform failed",);
+    assert_eq!(local.map(|vec,| vec * 3,).into_inner(), Vector::new(0, 3, 6,), "map failed",);
+    assert_eq!(local.retag::<View>().into_inner(), *local, "retag failed",);
+  }
+}
@@ -1,11 +1,17 @@
 //! Author --- daniel.bechaz@gmail.com  
-//! Last Moddified --- 2019-03-21
+//! Last Moddified --- 2026-10-19
 
 use crate::*;
-use std::{ops, cmp::Ordering,};
+#[cfg(feature = "fixed-point",)]
+use fixed_point::Unsigned;
+use core::{ops, fmt, slice, array, error::Error, cmp::Ordering, convert::TryFrom, str::FromStr,};
 
 /// A 3D Vector.
+/// 
+/// A Vector has the same memory layout as `[Num; 3]`.
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize,),)]
+#[repr(C,)]
 pub struct Vector<Num,> {
   /// The coordinate in the x dimention.
   pub x: Num,
@@ -19,6 +25,65 @@ impl<Num,> Vector<Num,> {
   /// Builds a new Vector value.
   #[inline]
   pub const fn new(x: Num, y: Num, z: Num,) -> Self { Self { x, y, z } }
+  /// Returns an iterator over the components of this Vector.
+  #[inline]
+  pub fn iter(&self,) -> slice::Iter<'_, Num,> { self.as_ref().iter() }
+  /// Returns a mutable iterator over the components of this Vector.
+  #[inline]
+  pub fn iter_mut(&mut self,) -> slice::IterMut<'_, Num,> { self.as_mut().iter_mut() }
+  /// Applies `f` to each component of this Vector.
+  /// 
+  /// # Params
+  /// 
+  /// f --- The function to apply.  
+  #[inline]
+  pub fn map<T, F,>(self, mut f: F,) -> Vector<T,>
+    where F: FnMut(Num,) -> T, {
+    Vector::new(f(self.x,), f(self.y,), f(self.z,),)
+  }
+  /// Combines the components of two Vectors using `f`.
+  /// 
+  /// # Params
+  /// 
+  /// rhs --- The Vector to combine with.  
+  /// f --- The function to combine each pair of components.  
+  #[inline]
+  pub fn zip_with<Rhs, T, F,>(self, rhs: Vector<Rhs,>, mut f: F,) -> Vector<T,>
+    where F: FnMut(Num, Rhs,) -> T, {
+    Vector::new(f(self.x, rhs.x,), f(self.y, rhs.y,), f(self.z, rhs.z,),)
+  }
+  /// Views a slice of Vectors as a flat slice of their components.
+  #[inline]
+  pub fn flatten_slice(vectors: &[Self],) -> &[Num] {
+    //`Vector` is `repr(C)` with three fields of the same type so it has no padding.
+    unsafe { slice::from_raw_parts(vectors.as_ptr() as *const Num, vectors.len() * 3,) }
+  }
+  /// Views a mutable slice of Vectors as a flat slice of their components.
+  #[inline]
+  pub fn flatten_slice_mut(vectors: &mut [Self],) -> &mut [Num] {
+    //`Vector` is `repr(C)` with three fields of the same type so it has no padding.
+    unsafe { slice::from_raw_parts_mut(vectors.as_mut_ptr() as *mut Num, vectors.len() * 3,) }
+  }
+  /// Views a flat slice of components as a slice of Vectors.
+  /// 
+  /// Returns `None` if the length of `components` is not a multiple of 3.
+  #[inline]
+  pub fn from_flat_slice(components: &[Num],) -> Option<&[Self]> {
+    if !components.len().is_multiple_of(3,) { return None }
+
+    //`Vector` has the same alignment as `Num` and the length is a multiple of 3.
+    Some(unsafe { slice::from_raw_parts(components.as_ptr() as *const Self, components.len() / 3,) })
+  }
+  /// Views a flat mutable slice of components as a slice of Vectors.
+  /// 
+  /// Returns `None` if the length of `components` is not a multiple of 3.
+  #[inline]
+  pub fn from_flat_slice_mut(components: &mut [Num],) -> Option<&mut [Self]> {
+    if !components.len().is_multiple_of(3,) { return None }
+
+    //`Vector` has the same alignment as `Num` and the length is a multiple of 3.
+    Some(unsafe { slice::from_raw_parts_mut(components.as_mut_ptr() as *mut Self, components.len() / 3,) })
+  }
 }
 
 impl<Num: Number,> Vector<Num,> {
@@ -27,8 +92,105 @@ impl<Num: Number,> Vector<Num,> {
   pub fn dot(lhs: Self, rhs: Self,) -> Num { lhs * rhs }
 }
 
+impl<Num: Number + Clone,> Vector<Num,> {
+  /// Returns the square of the magnituid of this Vector.
+  #[inline]
+  pub fn magnitude_squared(self,) -> Num { Self::dot(self.clone(), self,) }
+  /// Returns the square of the distance between two Vectors.
+  #[inline]
+  pub fn distance_squared(lhs: Self, rhs: Self,) -> Num { (lhs - rhs).magnitude_squared() }
+  /// Returns the scalar triple product `a . (b x c)` of three Vectors.
+  /// 
+  /// This is the signed volume of the parallelepiped spanned by the Vectors.
+  pub fn triple_product(a: Self, b: Self, c: Self,) -> Num {
+    (a.x * ((b.y.clone() * c.z.clone()) - (b.z.clone() * c.y.clone())))
+      - (a.y * ((b.x.clone() * c.z) - (b.z * c.x.clone())))
+      + (a.z * ((b.x * c.y) - (b.y * c.x)))
+  }
+  /// Linearly interpolates between this Vector and `to`.
+  /// 
+  /// # Params
+  /// 
+  /// to --- The Vector to interpolate towards.  
+  /// t --- The interpolation factor, `0` returns this Vector and `1` returns `to`.  
+  #[inline]
+  pub fn lerp(self, to: Self, t: Num,) -> Self { self.clone() + ((to - self) * t) }
+  /// Returns the component of this Vector which is parallel to `onto`.
+  /// 
+  /// # Params
+  /// 
+  /// onto --- The Vector to project onto, it must not have a length of zero.  
+  pub fn project_onto(self, onto: Self,) -> Self {
+    let scale = Self::dot(self, onto.clone(),) / onto.clone().magnitude_squared();
+
+    onto * scale
+  }
+  /// Returns the component of this Vector which is perpendicular to `from`.
+  /// 
+  /// # Params
+  /// 
+  /// from --- The Vector to reject from, it must not have a length of zero.  
+  #[inline]
+  pub fn reject_from(self, from: Self,) -> Self { self.clone() - self.project_onto(from,) }
+  /// Reflects this Vector off of a surface.
+  /// 
+  /// # Params
+  /// 
+  /// normal --- The normal of the surface being reflected off of.  
+  pub fn reflect(self, normal: Unit<Num,>,) -> Self {
+    let normal = normal.vector();
+    let scale = Self::dot(self.clone(), normal.clone(),) * Num::from_isize(2,);
+
+    self - (normal * scale)
+  }
+  /// Returns the component wise minimum of two Vectors.
+  pub fn min(lhs: Self, rhs: Self,) -> Self {
+    Self::new(
+      if rhs.x < lhs.x { rhs.x } else { lhs.x },
+      if rhs.y < lhs.y { rhs.y } else { lhs.y },
+      if rhs.z < lhs.z { rhs.z } else { lhs.z },
+    )
+  }
+  /// Returns the component wise maximum of two Vectors.
+  pub fn max(lhs: Self, rhs: Self,) -> Self {
+    Self::new(
+      if rhs.x > lhs.x { rhs.x } else { lhs.x },
+      if rhs.y > lhs.y { rhs.y } else { lhs.y },
+      if rhs.z > lhs.z { rhs.z } else { lhs.z },
+    )
+  }
+  /// Returns the component wise absolute value of this Vector.
+  pub fn abs(self,) -> Self {
+    Self::new(
+      if self.x < Num::ZERO { -self.x } else { self.x },
+      if self.y < Num::ZERO { -self.y } else { self.y },
+      if self.z < Num::ZERO { -self.z } else { self.z },
+    )
+  }
+  /// Clamps each component of this Vector between the components of `min` and `max`.
+  /// 
+  /// # Params
+  /// 
+  /// min --- The lower bound of each component.  
+  /// max --- The upper bound of each component.  
+  #[inline]
+  pub fn clamp(self, min: Self, max: Self,) -> Self { Self::min(Self::max(self, min,), max,) }
+}
+
 impl<Num,> Vector<Num,>
   where Num: Number + Sqrt + Trigonometry + Clone, {
+  /// Returns the angle between two Vectors in radians.
+  /// 
+  /// Neither Vector may have a length of zero.
+  pub fn angle_between(lhs: Self, rhs: Self,) -> Num {
+    let cos = Self::dot(lhs.clone(), rhs.clone(),) / (lhs.magnituid() * rhs.magnituid());
+    //Rounding errors can push the cosine outside of the domain of `acos`.
+    let cos = if cos > Num::ONE { Num::ONE }
+      else if cos < Num::NEG_ONE { Num::NEG_ONE }
+      else { cos };
+
+    cos.acos()
+  }
   /// Rotates this Vector.
   /// 
   /// # Params
@@ -54,9 +216,8 @@ impl<Num,> Vector<Num,>
     y dim is j
     z dim is k
     */
-    let angle = rotation.angle.clone() / Num::from_isize(2,);
-    let a = angle.clone().sin();
-    let mut bcd = rotation.axis.clone().vector() * angle.cos();
+    let (sin, a,) = (rotation.angle.clone() / Num::from_isize(2,)).sin_cos();
+    let mut bcd = rotation.axis.clone().vector() * sin;
     let temp = Vector::new(
       (a.clone() * self.x.clone()) + (bcd.y.clone() * self.z.clone())
         - (bcd.z.clone() * self.y.clone()),
@@ -84,24 +245,96 @@ impl<Num,> Vector<Num,>
 
 impl<Num,> Vector<Num,>
   where Num: Clone + ops::Mul<Output = Num> + ops::Sub<Output = Num>, {
-  /// Returns the dot product of two Vectors.
+  /// Returns the cross product of two Vectors.
   pub fn cross(lhs: Self, rhs: Self,) -> Self {
     let x = (lhs.y.clone() * rhs.z.clone()) - (lhs.z.clone() * rhs.y.clone());
-    let y = (lhs.x.clone() * rhs.z) - (lhs.z * rhs.x.clone());
+    let y = (lhs.z * rhs.x.clone()) - (lhs.x.clone() * rhs.z);
     let z = (lhs.x * rhs.y) - (lhs.y * rhs.x);
 
     Self { x, y, z, }
   }
 }
 
+impl<Num: ops::Mul<Output = Num>,> Vector<Num,> {
+  /// Returns the component wise product of two Vectors.
+  /// 
+  /// Multiplying two Vectors with `*` returns the dot product instead.
+  #[inline]
+  pub fn mul_elem(self, rhs: Self,) -> Self { self.zip_with(rhs, ops::Mul::mul,) }
+}
+
+impl<Num: ops::Div<Output = Num>,> Vector<Num,> {
+  /// Returns the component wise quotient of two Vectors.
+  #[inline]
+  pub fn div_elem(self, rhs: Self,) -> Self { self.zip_with(rhs, ops::Div::div,) }
+}
+
 impl<Num,> Vector<Num,>
   where Num: Sqrt + Clone, {
   /// Returns the magnituid of this Vector.
   #[inline]
   pub fn magnituid(self,) -> Num { Self::dot(self.clone(), self,).sqrt() }
+  /// Returns the distance between two Vectors.
+  #[inline]
+  pub fn distance(lhs: Self, rhs: Self,) -> Num { (lhs - rhs).magnituid() }
+  /// Refracts this Vector through a surface.
+  /// 
+  /// Returns `None` if the Vector is totally internally reflected.
+  /// 
+  /// # Params
+  /// 
+  /// normal --- The normal of the surface facing against this Vector.  
+  /// eta --- The ratio of the refractive indices, incident over transmitted.  
+  pub fn refract(self, normal: Unit<Num,>, eta: Num,) -> Option<Self> {
+    let normal = normal.vector();
+    let cos = Self::dot(self.clone(), normal.clone(),);
+    let k = Num::ONE - (eta.clone() * eta.clone() * (Num::ONE - (cos.clone() * cos.clone())));
+
+    if k < Num::ZERO { return None }
+
+    Some((self * eta.clone()) - (normal * ((eta * cos) + k.sqrt())))
+  }
   /// Converts this Vector into a unit Vector.
+  /// 
+  /// # Panics
+  /// 
+  /// If this Vector cannot be normalised, see [NormalizeError].
+  #[inline]
+  pub fn unit(self,) -> Unit<Num,> {
+    self.try_unit().expect("Cannot normalise the Vector")
+  }
+  /// Attempts to convert this Vector into a unit Vector.
   #[inline]
-  pub fn unit(self,) -> Unit<Num,> { self.into() }
+  pub fn try_unit(self,) -> Result<Unit<Num,>, NormalizeError> { Unit::try_from(self,) }
+  /// Converts this Vector into a unit Vector or returns `default` if this Vector cannot
+  /// be normalised.
+  /// 
+  /// # Params
+  /// 
+  /// default --- The [Unit] to return if this Vector cannot be normalised.  
+  #[inline]
+  pub fn normalize_or(self, default: Unit<Num,>,) -> Unit<Num,> {
+    self.try_unit().unwrap_or(default,)
+  }
+}
+
+impl<Num: Number,> Vector<Num,> {
+  /// The Vector with all components zero.
+  pub const ZERO: Self = Self::new(Num::ZERO, Num::ZERO, Num::ZERO,);
+  /// The Vector with all components one.
+  pub const ONE: Self = Self::new(Num::ONE, Num::ONE, Num::ONE,);
+  /// The unit Vector along the positive x axis.
+  pub const X: Self = Self::new(Num::ONE, Num::ZERO, Num::ZERO,);
+  /// The unit Vector along the positive y axis.
+  pub const Y: Self = Self::new(Num::ZERO, Num::ONE, Num::ZERO,);
+  /// The unit Vector along the positive z axis.
+  pub const Z: Self = Self::new(Num::ZERO, Num::ZERO, Num::ONE,);
+  /// The unit Vector along the negative x axis.
+  pub const NEG_X: Self = Self::new(Num::NEG_ONE, Num::ZERO, Num::ZERO,);
+  /// The unit Vector along the negative y axis.
+  pub const NEG_Y: Self = Self::new(Num::ZERO, Num::NEG_ONE, Num::ZERO,);
+  /// The unit Vector along the negative z axis.
+  pub const NEG_Z: Self = Self::new(Num::ZERO, Num::ZERO, Num::NEG_ONE,);
 }
 
 impl<Num: Default,> Vector<Num,> {
@@ -118,6 +351,68 @@ impl<Num,> From<(Num, Num, Num,)> for Vector<Num,> {
   fn from((x, y, z,): (Num, Num, Num,),) -> Self { Self { x, y, z, } }
 }
 
+impl<Num,> From<[Num; 3]> for Vector<Num,> {
+  #[inline]
+  fn from([x, y, z,]: [Num; 3],) -> Self { Self { x, y, z, } }
+}
+
+impl<Num,> From<Vector<Num,>> for [Num; 3] {
+  #[inline]
+  fn from(from: Vector<Num,>,) -> Self { [from.x, from.y, from.z,] }
+}
+
+impl<Num,> AsRef<[Num; 3]> for Vector<Num,> {
+  #[inline]
+  fn as_ref(&self,) -> &[Num; 3] {
+    //`Vector` is `repr(C)` with three fields of the same type so it has no padding.
+    unsafe { &*(self as *const Self as *const [Num; 3]) }
+  }
+}
+
+impl<Num,> AsMut<[Num; 3]> for Vector<Num,> {
+  #[inline]
+  fn as_mut(&mut self,) -> &mut [Num; 3] {
+    //`Vector` is `repr(C)` with three fields of the same type so it has no padding.
+    unsafe { &mut *(self as *mut Self as *mut [Num; 3]) }
+  }
+}
+
+impl<Num,> ops::Index<usize> for Vector<Num,> {
+  type Output = Num;
+
+  #[inline]
+  fn index(&self, index: usize,) -> &Self::Output { &self.as_ref()[index] }
+}
+
+impl<Num,> ops::IndexMut<usize> for Vector<Num,> {
+  #[inline]
+  fn index_mut(&mut self, index: usize,) -> &mut Self::Output { &mut self.as_mut()[index] }
+}
+
+impl<Num,> IntoIterator for Vector<Num,> {
+  type Item = Num;
+  type IntoIter = array::IntoIter<Num, 3>;
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { IntoIterator::into_iter(<[Num; 3]>::from(self,),) }
+}
+
+impl<'a, Num,> IntoIterator for &'a Vector<Num,> {
+  type Item = &'a Num;
+  type IntoIter = slice::Iter<'a, Num,>;
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, Num,> IntoIterator for &'a mut Vector<Num,> {
+  type Item = &'a mut Num;
+  type IntoIter = slice::IterMut<'a, Num,>;
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.iter_mut() }
+}
+
 impl<Num: PartialOrd,> PartialOrd for Vector<Num,> {
   fn partial_cmp(&self, rhs: &Self,) -> Option<Ordering> {
     self.x.partial_cmp(&rhs.x,)
@@ -216,19 +511,164 @@ impl<Num> ops::Mul for Vector<Num,>
   }
 }
 
+/// Implements scalar on the left multiplication of [Vector]s for primitive numbers.
+macro_rules! impl_scalar_mul {
+  ($($num:ty,)*) => {$(
+    impl ops::Mul<Vector<$num,>> for $num {
+      type Output = Vector<$num,>;
+
+      #[inline]
+      fn mul(self, rhs: Vector<$num,>,) -> Self::Output { rhs * self }
+    }
+  )*};
+}
+
+impl_scalar_mul!(isize, i8, i16, i32, i64, i128, f32, f64,);
+
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> ops::Mul<Vector<fixed_point::Fixed32<Shift,>,>> for fixed_point::Fixed32<Shift,> {
+  type Output = Vector<Self,>;
+
+  #[inline]
+  fn mul(self, rhs: Vector<Self,>,) -> Self::Output { rhs * self }
+}
+
+/// Included with feature "fixed-point".
+#[cfg(feature = "fixed-point",)]
+impl<Shift: Unsigned,> ops::Mul<Vector<fixed_point::Fixed64<Shift,>,>> for fixed_point::Fixed64<Shift,> {
+  type Output = Vector<Self,>;
+
+  #[inline]
+  fn mul(self, rhs: Vector<Self,>,) -> Self::Output { rhs * self }
+}
+
+/// Formats as `(x, y, z)`, formatting flags such as precision apply to each component.
+impl<Num: fmt::Display,> fmt::Display for Vector<Num,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    fmt.write_str("(",)?;
+    fmt::Display::fmt(&self.x, fmt,)?;
+    fmt.write_str(", ",)?;
+    fmt::Display::fmt(&self.y, fmt,)?;
+    fmt.write_str(", ",)?;
+    fmt::Display::fmt(&self.z, fmt,)?;
+    fmt.write_str(")",)
+  }
+}
+
+/// Parses either `(x, y, z)` or `x y z`.
+impl<Num: FromStr,> FromStr for Vector<Num,> {
+  type Err = ParseVectorError<Num::Err,>;
+
+  fn from_str(from: &str,) -> Result<Self, Self::Err> {
+    let from = from.trim();
+    let inner = match (from.strip_prefix('(',), from.ends_with(')',),) {
+      (Some(inner), true,) => &inner[..inner.len() - 1],
+      (None, false,) => from,
+      _ => return Err(ParseVectorError::Parentheses),
+    };
+    let (mut components, mut len,) = ([""; 3], 0,);
+    let mut push = |component,| {
+      if let Some(slot) = components.get_mut(len,) { *slot = component }
+      len += 1;
+    };
+
+    if inner.contains(',',) { inner.split(',',).map(str::trim,).for_each(&mut push,) }
+    else { inner.split_whitespace().for_each(&mut push,) }
+    if len != 3 { return Err(ParseVectorError::Components(len,)) }
+
+    let parse = |index: usize,| components[index].parse()
+      .map_err(|error,| ParseVectorError::Component(index, error,),);
+
+    Ok(Vector::new(parse(0,)?, parse(1,)?, parse(2,)?,))
+  }
+}
+
 /// A [Vector] with a length of 1 at all times.
+/// 
+/// A Unit has the same memory layout as `[Num; 3]`.
+/// 
+/// Deserialising a Unit fails if it does not have a length of 1.
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize,), serde(transparent,),)]
+#[repr(C,)]
 pub struct Unit<Num>(Vector<Num,>,);
 
 impl<Num> Unit<Num,> {
   /// Converts this [Unit] into a [Vector].
   #[inline]
   pub fn vector(self,) -> Vector<Num,> { self.0 }
+  /// Borrows this [Unit] as a [Vector].
+  #[inline]
+  pub fn as_vector(&self,) -> &Vector<Num,> { &self.0 }
+  /// Returns an iterator over the components of this [Unit].
+  #[inline]
+  pub fn iter(&self,) -> slice::Iter<'_, Num,> { self.0.iter() }
+}
+
+impl<Num: Number,> Unit<Num,> {
+  /// The [Unit] along the positive x axis.
+  pub const X: Self = Unit(Vector::X,);
+  /// The [Unit] along the positive y axis.
+  pub const Y: Self = Unit(Vector::Y,);
+  /// The [Unit] along the positive z axis.
+  pub const Z: Self = Unit(Vector::Z,);
+  /// The [Unit] along the negative x axis.
+  pub const NEG_X: Self = Unit(Vector::NEG_X,);
+  /// The [Unit] along the negative y axis.
+  pub const NEG_Y: Self = Unit(Vector::NEG_Y,);
+  /// The [Unit] along the negative z axis.
+  pub const NEG_Z: Self = Unit(Vector::NEG_Z,);
 }
 
-impl<Num: Sqrt + Clone,> From<Vector<Num,>> for Unit<Num,> {
+impl<Num: Number + Clone,> Unit<Num,> {
+  /// Returns `true` if `vector` has a length of 1 within rounding error.
+  fn is_unit(vector: &Vector<Num,>,) -> bool {
+    //Normalising rounds each component and squaring the length roughly doubles the error.
+    let tolerance = Num::EPSILON * Num::from_isize(16,);
+    let mag2 = Vector::dot(vector.clone(), vector.clone(),);
+
+    Num::ONE - tolerance.clone() <= mag2 && mag2 <= Num::ONE + tolerance
+  }
+  /// Wraps `vector` as a [Unit] without normalising it, if it has a length of 1.
+  /// 
+  /// # Params
+  /// 
+  /// vector --- The [Vector] to wrap.  
   #[inline]
-  fn from(from: Vector<Num,>,) -> Self { Unit(from.clone() / from.magnituid(),) }
+  pub(crate) fn checked(vector: Vector<Num,>,) -> Option<Self> {
+    if Self::is_unit(&vector,) { Some(Unit(vector,),) } else { None }
+  }
+}
+
+/// Included with feature "serde".
+#[cfg(feature = "serde",)]
+impl<'de, Num,> serde::Deserialize<'de> for Unit<Num,>
+  where Num: Number + Clone + serde::Deserialize<'de>, {
+  fn deserialize<D: serde::Deserializer<'de>,>(deserializer: D,) -> Result<Self, D::Error> {
+    Unit::checked(<Vector<Num,> as serde::Deserialize>::deserialize(deserializer,)?,)
+    .ok_or_else(|| serde::de::Error::custom("Unit does not have a length of 1",),)
+  }
+}
+
+impl<Num: Sqrt + Clone,> TryFrom<Vector<Num,>> for Unit<Num,> {
+  type Error = NormalizeError;
+
+  fn try_from(from: Vector<Num,>,) -> Result<Self, Self::Error> {
+    //Dividing by the largest component first stops the squared length overflowing or
+    //underflowing.
+    let scale = from.clone().abs().into_iter()
+      .fold(Num::ZERO, |max, num,| if num > max { num } else { max },);
+
+    //Also rejects `NaN` components.
+    if scale > Num::ZERO {
+      let scaled = from / scale;
+      let unit = scaled.clone() / scaled.magnituid();
+
+      if Self::is_unit(&unit,) { Ok(Unit(unit,)) } else { Err(NormalizeError::NotUnit) }
+    } else if from.into_iter().all(|num,| num == Num::ZERO,) { Err(NormalizeError::ZeroLength) }
+    else { Err(NormalizeError::NotUnit) }
+  }
 }
 
 impl<Num,> Into<Vector<Num,>> for Unit<Num,> {
@@ -236,14 +676,114 @@ impl<Num,> Into<Vector<Num,>> for Unit<Num,> {
   fn into(self,) -> Vector<Num,> { self.0 }
 }
 
+impl<Num,> From<Unit<Num,>> for [Num; 3] {
+  #[inline]
+  fn from(from: Unit<Num,>,) -> Self { from.0.into() }
+}
+
+impl<Num,> AsRef<[Num; 3]> for Unit<Num,> {
+  #[inline]
+  fn as_ref(&self,) -> &[Num; 3] { self.0.as_ref() }
+}
+
+impl<Num,> ops::Index<usize> for Unit<Num,> {
+  type Output = Num;
+
+  #[inline]
+  fn index(&self, index: usize,) -> &Self::Output { &self.0[index] }
+}
+
+impl<Num,> IntoIterator for Unit<Num,> {
+  type Item = Num;
+  type IntoIter = array::IntoIter<Num, 3>;
+
+  #[inline]
+  fn into_iter(self,) -> Self::IntoIter { self.0.into_iter() }
+}
+
 impl<Num: PartialEq,> PartialEq<Vector<Num,>> for Unit<Num,> {
   #[inline]
   fn eq(&self, rhs: &Vector<Num,>,) -> bool { self.0 == *rhs }
 }
 
+impl<Num: fmt::Display,> fmt::Display for Unit<Num,> {
+  #[inline]
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result { fmt::Display::fmt(&self.0, fmt,) }
+}
+
+/// Parses the same formats as [Vector], failing if the [Unit] does not have a length of 1.
+impl<Num: FromStr + Number + Clone,> FromStr for Unit<Num,> {
+  type Err = ParseVectorError<Num::Err,>;
+
+  fn from_str(from: &str,) -> Result<Self, Self::Err> {
+    Unit::checked(from.parse()?,).ok_or(ParseVectorError::NotUnit,)
+  }
+}
+
+/// The error returned when a [Vector] cannot be normalised.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash,)]
+pub enum NormalizeError {
+  /// The Vector has a length of zero.
+  ZeroLength,
+  /// The Vector has a non finite component or its normalised length is not 1 within
+  /// rounding error.
+  NotUnit,
+}
+
+impl fmt::Display for NormalizeError {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    match self {
+      NormalizeError::ZeroLength => fmt.write_str("cannot normalise a zero length Vector",),
+      NormalizeError::NotUnit => fmt.write_str("the normalised Vector does not have a length of 1",),
+    }
+  }
+}
+
+impl Error for NormalizeError {}
+
+/// The error returned when parsing a [Vector], [Unit] or [Rotation] fails.
+#[derive(PartialEq, Eq, Clone, Debug, Hash,)]
+pub enum ParseVectorError<Err,> {
+  /// An opening or closing parenthesis is unmatched.
+  Parentheses,
+  /// The number of components found, which was not 3.
+  Components(usize,),
+  /// The index of the component which failed to parse and the reason.
+  Component(usize, Err,),
+  /// The parsed [Unit] does not have a length of 1.
+  NotUnit,
+  /// The angle of a [Rotation] is missing.
+  MissingAngle,
+  /// The angle of a [Rotation] failed to parse.
+  Angle(Err,),
+}
+
+impl<Err: fmt::Display,> fmt::Display for ParseVectorError<Err,> {
+  fn fmt(&self, fmt: &mut fmt::Formatter,) -> fmt::Result {
+    match self {
+      ParseVectorError::Parentheses => fmt.write_str("unmatched parenthesis",),
+      ParseVectorError::Components(found,) => write!(fmt, "expected 3 components but found {}", found,),
+      ParseVectorError::Component(index, error,) => write!(fmt, "component {} is invalid: {}", index, error,),
+      ParseVectorError::NotUnit => fmt.write_str("a Unit does not have a length of 1",),
+      ParseVectorError::MissingAngle => fmt.write_str("the angle of the Rotation is missing",),
+      ParseVectorError::Angle(error,) => write!(fmt, "the angle is invalid: {}", error,),
+    }
+  }
+}
+
+impl<Err: Error + 'static,> Error for ParseVectorError<Err,> {
+  fn source(&self,) -> Option<&(dyn Error + 'static)> {
+    match self {
+      ParseVectorError::Component(_, error,) | ParseVectorError::Angle(error,) => Some(error,),
+      _ => None,
+    }
+  }
+}
+
 #[cfg(test,)]
 mod tests {
   use super::*;
+  use std::f64::consts::{PI, FRAC_PI_2,};
 
   #[test]
   fn test_vector() {
@@ -263,24 +803,229 @@ mod tests {
 
     assert_eq!(Vector::cross(x, y,), z, "Cross product failed 1",);
     assert_eq!(Vector::cross(y, z,), x, "Cross product failed 2",);
-    assert_eq!(Vector::cross(x, z,), y, "Cross product failed 3",);
+    assert_eq!(Vector::cross(z, x,), y, "Cross product failed 3",);
+    assert_eq!(Vector::cross(x, z,), -y, "Cross product failed 4",);
+    assert_eq!(Vector::cross(Vector::new(1, 2, 3,), Vector::new(-4, 5, 6,),), Vector::new(-3, -18, 13,), "Cross product failed 5",);
 
     const PI2: f32 = std::f32::consts::FRAC_PI_2;
-    const EPSILON: f32 = std::f32::EPSILON;
-
-    let rot = Rotation::new(z.into(), PI2,);
-    let vec = x.rotate(&rot,);
-    let diff = 1.0 - vec.y;
-    assert!(0.0 < diff && diff < EPSILON, "Rotate failed 1: {:?}", vec,);
-
-    let rot = Rotation::new(x.into(), PI2,);
-    let vec = y.rotate(&rot,);
-    let diff = 1.0 - vec.z;
-    assert!(0.0 < diff && diff < EPSILON, "Rotate failed 2: {:?}", vec,);
-
-    let rot = Rotation::new(y.into(), PI2,);
-    let vec = z.rotate(&rot,);
-    let diff = 1.0 - vec.x;
-    assert!(0.0 < diff && diff < EPSILON, "Rotate failed 3: {:?}", vec,);
+
+    let rot = Rotation::new(z.unit(), Radians(PI2,),);
+    assert_approx_eq!(x.rotate(&rot,), y, "Rotate failed 1",);
+
+    let rot = Rotation::new(x.unit(), Radians(PI2,),);
+    assert_approx_eq!(y.rotate(&rot,), z, "Rotate failed 2",);
+
+    let rot = Rotation::new(y.unit(), Radians(PI2,),);
+    assert_approx_eq!(z.rotate(&rot,), x, "Rotate failed 3",);
+
+    let rot = Rotation::new(z.unit(), Radians(0.0,),);
+    assert_approx_eq!(x.rotate(&rot,), x, "Rotate failed 4",);
+
+    let rot = Rotation::new(z.unit(), Radians(PI2 / 3.0,),);
+    assert_approx_eq!(x.rotate(&rot,), Vector::new(0.75f32.sqrt(), 0.5, 0.0,), "Rotate failed 5",);
+  }
+  #[test]
+  fn test_rotate() {
+    const PI3: f64 = std::f64::consts::FRAC_PI_3;
+
+    //`pi / 3` and `pi - pi / 3` rotate to different Vectors.
+    let rot = Rotation::new(Unit::Z, Radians(PI3,),);
+    assert_abs_diff_eq!(Vector::X.rotate(&rot,), Vector::new(0.5, 0.75f64.sqrt(), 0.0,), 1e-12, "Rotate failed 1",);
+
+    //Compare against Rodrigues' rotation formula about an oblique axis.
+    let (axis, angle,) = (Vector::new(1.0, -2.0, 0.5,).unit(), 2.0f64,);
+    let vec = Vector::new(-3.0, 0.25, 4.0,);
+    let k = axis.vector();
+    let expected = (vec * angle.cos()) + (Vector::cross(k, vec,) * angle.sin()) + (k * ((k * vec) * (1.0 - angle.cos())));
+    assert_abs_diff_eq!(vec.rotate(&Rotation::new(axis, Radians(angle,),),), expected, 1e-12, "Rotate failed 2",);
+  }
+  #[test]
+  fn test_scalar_mul() {
+    let vec = Vector::new(1, 2, 3,);
+
+    assert_eq!(2 * vec, vec * 2, "Scalar multiplication failed",);
+    assert_eq!(2.0 * Vector::new(1.0, 2.0, 3.0,), Vector::new(2.0, 4.0, 6.0,), "Scalar multiplication f64 failed",);
+    assert_eq!(vec.mul_elem(Vector::new(3, 2, 1,),), Vector::new(3, 4, 3,), "mul_elem failed",);
+    assert_eq!(Vector::new(3, 4, 3,).div_elem(Vector::new(3, 2, 1,),), vec, "div_elem failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_scalar_mul_fixed() {
+    use fixed_point::{Fixed32, consts::U16,};
+
+    let vec = Vector::<Fixed32<U16,>,>::new(1.into(), 2.into(), 3.into(),);
+    let two = Fixed32::from(2,);
+
+    assert_eq!(two * vec, vec * two, "Scalar multiplication Fixed32 failed",);
+  }
+  #[cfg(feature = "fixed-point",)]
+  #[test]
+  fn test_unit_fixed() {
+    use fixed_point::{Fixed32, consts::U16,};
+
+    type Fixed = Fixed32<U16,>;
+
+    let epsilon = Fixed::from(0.001f32,);
+    let expected = Vector::new(0.6f32, 0.0, -0.8,).map(Fixed::from,);
+    //The squared length of both Vectors is outside the range of Fixed32.
+    let long = Vector::new(18_000, 0, -24_000,).map(Fixed::from,);
+    let short = Vector::new(3, 0, -4,).map(Fixed::from_bits,);
+
+    assert_abs_diff_eq!(long.unit(), expected, epsilon, "Long Fixed32 normalisation failed",);
+    assert_abs_diff_eq!(short.unit(), expected, epsilon, "Short Fixed32 normalisation failed",);
+    assert_eq!(Vector::<Fixed,>::ZERO.try_unit(), Err(NormalizeError::ZeroLength), "Zero Fixed32 length accepted",);
+  }
+  #[test]
+  fn test_geometry() {
+    let vec = Vector::new(1.0, 2.0, 3.0,);
+    let other = Vector::new(4.0, 6.0, 3.0,);
+
+    assert_eq!(vec.magnitude_squared(), 14.0, "magnitude_squared failed",);
+    assert_eq!(Vector::distance_squared(vec, other,), 25.0, "distance_squared failed",);
+    assert_approx_eq!(Vector::distance(vec, other,), 5.0, "distance failed",);
+    assert_eq!(vec.lerp(other, 0.5,), Vector::new(2.5, 4.0, 3.0,), "lerp failed",);
+    assert_eq!(vec.project_onto(Vector::new(0.0, 2.0, 0.0,),), Vector::new(0.0, 2.0, 0.0,), "project_onto failed",);
+    assert_eq!(vec.reject_from(Vector::new(0.0, 2.0, 0.0,),), Vector::new(1.0, 0.0, 3.0,), "reject_from failed",);
+
+    let x = Vector::new(1.0, 0.0, 0.0,);
+    let y = Vector::new(0.0, 1.0, 0.0,);
+    let z = Vector::new(0.0, 0.0, 1.0,);
+
+    assert_eq!(Vector::triple_product(x, y, z,), 1.0, "triple_product failed 1",);
+    assert_eq!(Vector::triple_product(y, x, z,), -1.0, "triple_product failed 2",);
+    assert_approx_eq!(Vector::angle_between(x, y,), FRAC_PI_2, "angle_between failed 1",);
+    assert_approx_eq!(Vector::angle_between(x, -x,), PI, "angle_between failed 2",);
+    assert_eq!(Vector::angle_between(x, x * 3.0,), 0.0, "angle_between failed 3",);
+
+    let incident = Vector::new(1.0, -1.0, 0.0,);
+    assert_eq!(incident.reflect(y.unit(),), Vector::new(1.0, 1.0, 0.0,), "reflect failed",);
+
+    let incident = incident.unit().vector();
+    assert_approx_eq!(incident.refract(y.unit(), 1.0,).unwrap(), incident, "refract failed 1",);
+    let refracted = incident.refract(y.unit(), 1.0 / 1.5,).unwrap();
+    assert_approx_eq!(refracted.magnituid(), 1.0, "refract failed 2",);
+    assert_approx_eq!(refracted.x, incident.x / 1.5, "refract failed 3",);
+    assert!(incident.refract(y.unit(), 1.5,).is_none(), "refract failed 4",);
+
+    let vec = Vector::new(-1, 5, -3,);
+    let other = Vector::new(2, 1, -4,);
+    assert_eq!(Vector::min(vec, other,), Vector::new(-1, 1, -4,), "min failed",);
+    assert_eq!(Vector::max(vec, other,), Vector::new(2, 5, -3,), "max failed",);
+    assert_eq!(vec.abs(), Vector::new(1, 5, 3,), "abs failed",);
+    assert_eq!(
+      vec.clamp(Vector::new(0, 0, 0,), Vector::new(2, 2, 2,),), Vector::new(0, 2, 0,),
+      "clamp failed",
+    );
+  }
+  #[test]
+  fn test_layout() {
+    let mut vec = Vector::from([1, 2, 3,],);
+
+    assert_eq!(vec, Vector::new(1, 2, 3,), "From array failed",);
+    assert_eq!(<[i32; 3]>::from(vec,), [1, 2, 3,], "Into array failed",);
+    assert_eq!(vec.as_ref(), &[1, 2, 3,], "as_ref failed",);
+    assert_eq!((vec[0], vec[1], vec[2],), (1, 2, 3,), "Index failed",);
+
+    vec[1] = 5;
+    assert_eq!(vec, Vector::new(1, 5, 3,), "IndexMut failed",);
+    assert_eq!(vec.iter().sum::<i32>(), 9, "iter failed",);
+    for num in &mut vec { *num *= 2 }
+    assert_eq!(vec.into_iter().collect::<Vec<_>>(), vec![2, 10, 6,], "IntoIterator failed",);
+    assert_eq!(vec.map(|num,| num as f32 / 2.0,), Vector::new(1.0, 5.0, 3.0,), "map failed",);
+    assert_eq!(vec.zip_with(Vector::new(1, 2, 3,), |a, b,| a - b,), Vector::new(1, 8, 3,), "zip_with failed",);
+
+    let mut vectors = [Vector::new(1.0f32, 2.0, 3.0,), Vector::new(4.0, 5.0, 6.0,),];
+    assert_eq!(Vector::flatten_slice(&vectors,), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0,], "flatten_slice failed",);
+    Vector::flatten_slice_mut(&mut vectors,)[4] = 0.0;
+    assert_eq!(vectors[1], Vector::new(4.0, 0.0, 6.0,), "flatten_slice_mut failed",);
+
+    let flat = [1, 2, 3, 4, 5, 6,];
+    assert_eq!(Vector::from_flat_slice(&flat,), Some(&[Vector::new(1, 2, 3,), Vector::new(4, 5, 6,),][..]), "from_flat_slice failed",);
+    assert_eq!(Vector::from_flat_slice(&flat[1..],), None, "from_flat_slice accepted a partial Vector",);
+  }
+  #[test]
+  #[should_panic]
+  fn test_index_out_of_bounds() { let _ = Vector::new(1, 2, 3,)[3]; }
+  #[test]
+  fn test_constants() {
+    assert_eq!(Vector::<i32,>::ZERO, Vector::new(0, 0, 0,), "ZERO failed",);
+    assert_eq!(Vector::<f32,>::ONE, Vector::new(1.0, 1.0, 1.0,), "ONE failed",);
+    assert_eq!(Vector::X + Vector::NEG_X, Vector::<f64,>::ZERO, "NEG_X failed",);
+    assert_eq!(Vector::cross(Vector::<i32,>::X, Vector::Y,), Vector::Z, "Axes failed",);
+    assert_eq!(Unit::<f32,>::Y, Vector::Y, "Unit Y failed",);
+    assert_eq!(-Unit::<f32,>::NEG_Z.vector(), Vector::Z, "Unit NEG_Z failed",);
+    assert_eq!(Vector::<f32,>::origin(), Vector::ZERO, "origin failed",);
+  }
+  #[test]
+  fn test_unit() {
+    let vec = Vector::new(3.0, 0.0, 4.0,);
+
+    assert_approx_eq!(vec.unit(), Vector::new(0.6, 0.0, 0.8,), "Normalisation failed",);
+    assert_eq!(Vector::new(0.0, 0.0, 0.0,).try_unit(), Err(NormalizeError::ZeroLength), "Zero length accepted",);
+    assert_eq!(Vector::new(f32::NAN, 0.0, 0.0,).try_unit(), Err(NormalizeError::NotUnit), "NaN length accepted",);
+    assert_eq!(Vector::new(f32::INFINITY, 0.0, 0.0,).try_unit(), Err(NormalizeError::NotUnit), "Infinite length accepted",);
+    assert_eq!(Vector::new(1e20f32, 0.0, 0.0,).try_unit(), Ok(Unit::X), "Long normalisation failed",);
+    assert_approx_eq!(Vector::new(3e30f32, 0.0, -4e30,).unit(), Vector::new(0.6, 0.0, -0.8,), "Long normalisation failed 2",);
+    assert_eq!(Vector::new(0.0, -1e-30f32, 0.0,).try_unit(), Ok(Unit::NEG_Y), "Short normalisation failed",);
+    assert_eq!(Vector::new(0.0, 0.0, 1e-45f32,).try_unit(), Ok(Unit::Z), "Subnormal normalisation failed",);
+    assert_eq!(
+      Vector::new(0.0, 0.0, 0.0,).normalize_or(vec.unit(),), vec.unit(),
+      "normalize_or failed",
+    );
+  }
+  #[test]
+  #[should_panic]
+  fn test_unit_zero() { Vector::new(0.0, 0.0, 0.0,).unit(); }
+  #[test]
+  fn test_format() {
+    assert_eq!(Vector::new(1, 2, 3,).to_string(), "(1, 2, 3)", "Display failed 1",);
+    assert_eq!(format!("{:.2}", Vector::new(1.0, 0.5, -2.0,),), "(1.00, 0.50, -2.00)", "Display failed 2",);
+    assert_eq!(format!("{:.1}", Unit::<f32>::NEG_Z,), "(0.0, 0.0, -1.0)", "Unit display failed",);
+
+    assert_eq!("(1, 2, 3)".parse(), Ok(Vector::new(1, 2, 3,)), "Parse failed 1",);
+    assert_eq!(" 1  -2\t3 ".parse(), Ok(Vector::new(1, -2, 3,)), "Parse failed 2",);
+    assert_eq!("(1.5,-2,3e2)".parse(), Ok(Vector::new(1.5, -2.0, 300.0,)), "Parse failed 3",);
+    assert_eq!("(0, 1, 0)".parse(), Ok(Unit::<f64>::Y), "Unit parse failed",);
+
+    assert_eq!("(1, 2, 3".parse::<Vector<i32>>(), Err(ParseVectorError::Parentheses), "Parentheses accepted",);
+    assert_eq!("1 2".parse::<Vector<i32>>(), Err(ParseVectorError::Components(2,)), "Components accepted",);
+    assert!(matches!("1, x, 3".parse::<Vector<i32>>(), Err(ParseVectorError::Component(1, _,)),), "Component accepted",);
+    assert_eq!("1 1 0".parse::<Unit<f64>>(), Err(ParseVectorError::NotUnit), "Non unit accepted",);
+    assert_eq!("0.95 0 0".parse::<Unit<f64>>(), Err(ParseVectorError::NotUnit), "Short unit accepted",);
+  }
+  #[cfg(feature = "serde",)]
+  #[test]
+  fn test_serde() {
+    let vector = Vector::new(1.0, -2.5, 0.125,);
+    let json = serde_json::to_string(&vector,).expect("Serialise failed");
+    assert_eq!(json, r#"{"x":1.0,"y":-2.5,"z":0.125}"#, "Vector serialise failed",);
+    assert_eq!(serde_json::from_str::<Vector<f64>>(&json,).ok(), Some(vector), "Vector deserialise failed",);
+
+    let json = serde_json::to_string(&Unit::<f32>::Y,).expect("Serialise failed");
+    assert_eq!(serde_json::from_str::<Unit<f32>>(&json,).ok(), Some(Unit::Y), "Unit deserialise failed",);
+    assert!(serde_json::from_str::<Unit<f32>>(r#"{"x":1.0,"y":1.0,"z":0.0}"#,).is_err(), "Non unit accepted",);
+    assert!(serde_json::from_str::<Unit<f64>>(r#"{"x":0.95,"y":0.0,"z":0.0}"#,).is_err(), "Short unit accepted",);
+    let unit = Vector::new(1.0, -2.0, 0.5,).unit();
+    let json = serde_json::to_string(&unit,).expect("Serialise failed");
+    assert_eq!(serde_json::from_str::<Unit<f64>>(&json,).ok(), Some(unit), "Normalised unit deserialise failed",);
+
+    let rotation = Rotation::new(Unit::Z, Radians(0.5f64,),);
+    let json = serde_json::to_string(&rotation,).expect("Serialise failed");
+    let decoded = serde_json::from_str::<Rotation<f64>>(&json,).expect("Rotation deserialise failed");
+    assert_eq!((decoded.axis, decoded.angle,), (rotation.axis, rotation.angle,), "Rotation round trip failed",);
+  }
+  #[cfg(all(feature = "serde", feature = "fixed-point",),)]
+  #[test]
+  fn test_serde_fixed() {
+    use fixed_point::{Fixed32, Fixed64, consts::U16,};
+
+    let vector = Vector::<Fixed32<U16>>::new(1.into(), (-2).into(), 0.5.into(),);
+    let json = serde_json::to_string(&vector,).expect("Serialise failed");
+    assert_eq!(json, r#"{"x":65536,"y":-131072,"z":32768}"#, "Fixed32 serialise failed",);
+    assert_eq!(serde_json::from_str::<Vector<Fixed32<U16>>>(&json,).ok(), Some(vector), "Fixed32 deserialise failed",);
+
+    let unit = Unit::<Fixed64<U16>>::NEG_X;
+    let json = serde_json::to_string(&unit,).expect("Serialise failed");
+    assert_eq!(serde_json::from_str::<Unit<Fixed64<U16>>>(&json,).ok(), Some(unit), "Fixed64 deserialise failed",);
   }
 }